@@ -0,0 +1,22 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+use codec::Codec;
+use sp_std::vec::Vec;
+use pallet_paratensor::{HyperparameterRanges, NeuronInfo, SubnetHyperparams, UnbondingChunk};
+
+sp_api::decl_runtime_apis! {
+	/// ---- Read only queries into the paratensor pallet for tooling.
+	pub trait ParatensorApi<AccountId> where AccountId: Codec {
+		/// ---- Returns the inclusive (min, max) range accepted for each hyperparameter.
+		fn get_hyperparameter_ranges() -> HyperparameterRanges;
+
+		/// ---- Returns the hyperparameters of the network, or None if it has not been added.
+		fn get_subnet_hyperparams( netuid: u16 ) -> Option<SubnetHyperparams>;
+
+		/// ---- Returns the chunks of removed stake the coldkey has unbonding.
+		fn get_unbonding_chunks( coldkey: AccountId ) -> Vec<UnbondingChunk<AccountId>>;
+
+		/// ---- Returns the info of the neuron under uid on the network, including its validator
+		/// permit and registration block, or None if the uid is not in use.
+		fn get_neuron_info( netuid: u16, uid: u16 ) -> Option<NeuronInfo<AccountId>>;
+	}
+}
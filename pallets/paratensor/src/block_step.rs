@@ -0,0 +1,132 @@
+use super::*;
+use frame_support::weights::Weight;
+use frame_support::traits::Get;
+use frame_support::storage::IterableStorageMap;
+
+impl<T: Config> Pallet<T> {
+
+    /// ---- Distributes this step's emission across the added subnetworks and runs the
+    /// epoch on each subnetwork whose tempo has come due. Emission owed to a subnetwork
+    /// between its epochs is accumulated in PendingEmission and paid out when the epoch runs.
+    ///
+    /// # Args:
+    /// 	* 'emission_this_step' (u64):
+    /// 		- The total emission minted over this step, split by EmissionRatio.
+    ///
+    /// # Returns:
+    /// 	* 'Weight':
+    /// 		- The weight consumed by this step.
+    ///
+    pub fn mechanism_step( emission_this_step: u64 ) -> Weight {
+        let mut weight: Weight = 0;
+
+        // --- 1. Iterate over every added subnetwork which has registered neurons.
+        for ( netuid, added ) in <NetworksAdded<T> as IterableStorageMap<u16, bool>>::iter() {
+            weight = weight.saturating_add( T::DbWeight::get().reads( 1 ) );
+            if !added { continue }
+            let n: u16 = Self::get_subnetwork_n( netuid );
+            weight = weight.saturating_add( T::DbWeight::get().reads( 1 ) );
+            if n == 0 { continue }
+
+            // --- 2. Accumulate this subnetwork's share of the step emission.
+            let subnet_emission: u64 = Self::get_emission_for_subnet( netuid, emission_this_step );
+            PendingEmission::<T>::mutate( netuid, |pending| *pending = pending.saturating_add( subnet_emission ) );
+            weight = weight.saturating_add( T::DbWeight::get().reads_writes( 2, 1 ) );
+
+            // --- 3. Run the epoch if the subnetwork's tempo has come due.
+            let steps_since_last_epoch: u64 = StepsSinceLastEpoch::<T>::get( netuid ) + 1;
+            weight = weight.saturating_add( T::DbWeight::get().reads_writes( 2, 1 ) );
+            if steps_since_last_epoch >= Self::get_steps_per_epoch( netuid ) {
+                let pending_emission: u64 = PendingEmission::<T>::take( netuid );
+                Self::epoch( netuid, pending_emission, false );
+                StepsSinceLastEpoch::<T>::insert( netuid, 0 );
+                weight = weight.saturating_add( Self::get_epoch_weight( n ) );
+            } else {
+                StepsSinceLastEpoch::<T>::insert( netuid, steps_since_last_epoch );
+            }
+        }
+
+        // --- 4. Record the block of this step.
+        LastMechansimStepBlock::<T>::set( Self::get_current_block_as_u64() );
+        weight.saturating_add( T::DbWeight::get().writes( 1 ) )
+    }
+
+    /// ---- Clears the registration counters of every subnetwork so that MaxRegistrationsPerBlock
+    /// applies to each block separately.
+    pub fn reset_registrations_this_block() -> Weight {
+        let cleared: u64 = RegistrationsThisBlock::<T>::drain().count() as u64;
+        T::DbWeight::get().reads_writes( cleared, cleared )
+    }
+
+    /// ---- Retargets the registration difficulty of each subnetwork at the end of its adjustment interval.
+    /// The difficulty moves towards the target registrations per interval, i.e.
+    /// next = current * ( registrations + target ) / ( 2 * target ), clamped to the
+    /// MinimumDifficulty and MaximumDifficulty bounds. The interval counter is then reset.
+    ///
+    /// # Returns:
+    /// 	* 'Weight':
+    /// 		- The weight consumed by the update.
+    ///
+    pub fn update_difficulty() -> Weight {
+        let mut weight: Weight = 0;
+        let current_block: u64 = Self::get_current_block_as_u64();
+        for ( netuid, _ ) in <SubnetworkN<T> as IterableStorageMap<u16, u16>>::iter() {
+            let adjustment_interval: u64 = Self::get_adjustment_interval( netuid ) as u64;
+            weight = weight.saturating_add( T::DbWeight::get().reads( 2 ) );
+            if adjustment_interval == 0 || current_block % adjustment_interval != 0 { continue }
+
+            let target_registrations: u128 = Self::get_target_registrations_per_interval( netuid ) as u128;
+            let registrations_this_interval: u128 = Self::get_registrations_this_interval( netuid ) as u128;
+            let current_difficulty: u128 = Self::get_difficulty_as_u64( netuid ) as u128;
+            let mut next_difficulty: u128 = current_difficulty;
+            if target_registrations > 0 {
+                next_difficulty = current_difficulty * ( registrations_this_interval + target_registrations ) / ( 2 * target_registrations );
+            }
+            let next_difficulty: u64 = next_difficulty.min( T::MaximumDifficulty::get() as u128 ).max( T::MinimumDifficulty::get() as u128 ) as u64;
+            Self::set_difficulty_from_u64( netuid, next_difficulty );
+            RegistrationsThisInterval::<T>::insert( netuid, 0 );
+            Self::deposit_event( Event::DifficultySet( netuid, next_difficulty ) );
+            weight = weight.saturating_add( T::DbWeight::get().reads_writes( 3, 2 ) );
+        }
+        weight
+    }
+
+    /// ---- Mints each neuron's emission onto the stake of its hotkey. Stake, S and TotalStake are
+    /// updated through add_stake_to_neuron_hotkey_account and the amount credited is added to TotalIssuance.
+    ///
+    /// # Args:
+    /// 	* 'netuid' (u16):
+    /// 		- The network the emission was computed on.
+    ///
+    /// 	* 'emission' (&Vec<u64>):
+    /// 		- The emission owed to each uid on the network.
+    ///
+    pub fn distribute_emission( netuid: u16, emission: &Vec<u64> ) {
+        for ( uid_i, emission_i ) in emission.iter().enumerate() {
+            if *emission_i == 0 { continue }
+            let hotkey: T::AccountId = Self::get_hotkey_for_net_and_neuron( netuid, uid_i as u16 );
+            let credited: u64 = Self::emit_inflation_through_hotkey_account( &hotkey, *emission_i );
+            Self::increase_total_issuance( credited );
+        }
+    }
+
+    /// ---- Returns the share of the passed emission owed to this subnetwork. EmissionRatio values
+    /// are proportions of u16::MAX.
+    pub fn get_emission_for_subnet( netuid: u16, emission: u64 ) -> u64 {
+        let ratio: u128 = EmissionRatio::<T>::get( netuid ) as u128;
+        ( emission as u128 * ratio / u16::MAX as u128 ) as u64
+    }
+
+    /// ---- Returns the number of steps between epochs on this subnetwork. A tempo of zero
+    /// runs the epoch every step.
+    pub fn get_steps_per_epoch( netuid: u16 ) -> u64 {
+        let tempo: u64 = Self::get_tempo( netuid ) as u64;
+        if tempo == 0 { 1 } else { tempo }
+    }
+
+    /// ---- Returns an estimate of the weight of running the epoch over n neurons.
+    /// The epoch reads the keys, stake, weights and bonds rows of each neuron.
+    pub fn get_epoch_weight( n: u16 ) -> Weight {
+        T::DbWeight::get().reads( 4 * n as u64 )
+    }
+}
@@ -3,22 +3,47 @@ use sp_runtime::sp_std::if_std;
 use frame_support::sp_std::vec;
 use frame_support::inherent::Vec;
 use substrate_fixed::transcendental::exp;
-use substrate_fixed::types::I32F32;
+use substrate_fixed::types::{I32F32, I96F32};
 use frame_support::storage::IterableStorageDoubleMap;
 
 impl<T: Config> Pallet<T> {
-    pub fn epoch( netuid: u16, _total_emission: u64, debug: bool ) {
-        /*TO DO:
-        1. calculate node to prune
-        2. update all other nodes consensus parameters including bonds and weights */
+    pub fn epoch( netuid: u16, total_emission: u64, debug: bool ) {
+        // Clear the bonds held in uids which were replaced since the last epoch.
+        let uids_to_prune: Vec<u16> = NeuronsToPruneAtNextEpoch::<T>::take( netuid );
+        if uids_to_prune.len() > 0 { Self::clear_bonds_in_uids( netuid, &uids_to_prune ); }
+
+        // Nothing to compute on an empty network.
+        let n: usize = Self::get_subnetwork_n( netuid ) as usize;
+        if n == 0 { return }
+
+        // Neurons which have not set weights within the activity cutoff are inactive.
+        let active: Vec<bool> = Self::compute_active( netuid );
+        Active::<T>::insert( netuid, active.clone() );
+        if debug { if_std! { println!( "A:\n{:?}\n", active.clone() );}}
 
         // Access network stake as normalized vector.
         let mut stake: Vec<I32F32> = Self::get_stake( netuid );
         Self::inplace_normalize( &mut stake );
         if debug { if_std! { println!( "S:\n{:?}\n", stake.clone() );}}
 
-        // Access network weights row normalized.
-        let weights: Vec<Vec<I32F32>> = Self::get_weights( netuid );
+        // The neurons with the most stake hold a validator permit.
+        let max_allowed_validators: usize = Self::get_max_allowed_validators( netuid ) as usize;
+        let validator_permit: Vec<bool> = Self::is_topk( &stake, max_allowed_validators );
+        ValidatorPermit::<T>::insert( netuid, validator_permit.clone() );
+        if debug { if_std! { println!( "vP:\n{:?}\n", validator_permit.clone() );}}
+
+        // Only active neurons holding a permit validate.
+        let validators: Vec<bool> = active.iter().zip( validator_permit.iter() ).map( |(ai, pi)| *ai && *pi ).collect();
+
+        // Only the stake of validators counts towards ranks and trust.
+        let mut active_stake: Vec<I32F32> = stake.clone();
+        Self::inplace_mask_vector( &validators, &mut active_stake );
+        Self::inplace_normalize( &mut active_stake );
+        if debug { if_std! { println!( "aS:\n{:?}\n", active_stake.clone() );}}
+
+        // Access network weights row normalized, dropping the rows of neurons which do not validate.
+        let mut weights: Vec<Vec<I32F32>> = Self::get_weights( netuid );
+        Self::inplace_mask_rows( &validators, &mut weights );
         if debug { if_std! { println!( "W:\n{:?}\n", weights.clone() );}}
 
         // Acess network bonds row normalized.
@@ -26,44 +51,92 @@ impl<T: Config> Pallet<T> {
         if debug { if_std! { println!( "B:\n{:?}\n", bonds.clone() );}}
 
         // Compute ranks.
-        let ranks: Vec<I32F32> = Self::matmul( &weights, &stake );
+        let ranks: Vec<I32F32> = Self::matmul( &weights, &active_stake );
         if debug { if_std! { println!( "R:\n{:?}\n", ranks.clone() );}}
 
         // Compute thresholded weights.
         let upper: I32F32 = I32F32::from_num( 1.0 );
         let lower: I32F32 = I32F32::from_num( 0.0 );
-        let threshold: I32F32 = I32F32::from_num( 0.01 );
+        let threshold: I32F32 = Self::get_float_trust_threshold( netuid );
         let clipped_weights: Vec<Vec<I32F32>> = Self::clip( &weights, threshold, upper, lower );
         if debug { if_std! { println!( "tW:\n{:?}\n", clipped_weights.clone() );}}
 
         // Compute trust scores.
-        let trust: Vec<I32F32> = Self::matmul( &clipped_weights, &stake );
+        let trust: Vec<I32F32> = Self::matmul( &clipped_weights, &active_stake );
         if debug { if_std! { println!( "T:\n{:?}\n", trust.clone() );}}
 
         // Compute consensus.
         let one: I32F32 = I32F32::from_num(1.0);
-        let rho: I32F32 = I32F32::from_num(10.0);
-        let kappa: I32F32 = I32F32::from_num(0.5);
+        let rho: I32F32 = Self::get_float_rho( netuid );
+        let kappa: I32F32 = Self::get_float_kappa( netuid );
         let exp_trust: Vec<I32F32> = trust.iter().map( |t|  exp( -rho * (t - kappa) ).expect("") ).collect();
         let consensus: Vec<I32F32> = exp_trust.iter().map( |t|  one /(one + t) ).collect();
         if debug { if_std! { println!( "C:\n{:?}\n", consensus.clone() );}}
 
         // Compute incentive.
-        let mut incentive: Vec<I32F32> = ranks.iter().zip( consensus ).map( |(ri, ci)| ri * ci ).collect();
+        let mut incentive: Vec<I32F32> = ranks.iter().zip( consensus.iter() ).map( |(ri, ci)| ri * ci ).collect();
         Self::inplace_normalize( &mut incentive );
         if debug { if_std! { println!( "I:\n{:?}\n", incentive.clone() );}}
 
-        // Compute dividends.
-        let dividends: Vec<I32F32> = Self::matmul( &bonds, &incentive );
+        // Compute dividends, inactive neurons earn none.
+        let mut dividends: Vec<I32F32> = Self::matmul_transpose( &bonds, &incentive );
+        Self::inplace_mask_vector( &active, &mut dividends );
         if debug { if_std! { println!( "D:\n{:?}\n", dividends.clone() );}}
     
         // Compute bonds moving average.
-        let alpha: I32F32 = I32F32::from_num( 0.9 );
-        let ema_bonds: Vec<Vec<I32F32>> = Self::mat_ema( &weights, &bonds, alpha );
+        let alpha: I32F32 = Self::get_float_bonds_alpha( netuid );
+        let mut ema_bonds: Vec<Vec<I32F32>> = Self::mat_ema( &weights, &bonds, alpha );
+        Self::inplace_mask_rows( &validator_permit, &mut ema_bonds ); // Bonds are only held by permitted validators.
         if debug { if_std! { println!( "emaB:\n{:?}\n", ema_bonds.clone() );}}
 
-        // *TODO: Compute emissions.
+        // Compute emissions as the normalized sum of incentive and dividends.
+        let mut emission_proportions: Vec<I32F32> = incentive.iter().zip( dividends.iter() ).map( |(ii, di)| ii + di ).collect();
+        Self::inplace_normalize( &mut emission_proportions );
+        let emission: Vec<u64> = emission_proportions.iter().map( |ei| ( I96F32::from_num( *ei ) * I96F32::from_num( total_emission ) ).to_num::<u64>() ).collect();
+        if debug { if_std! { println!( "E:\n{:?}\n", emission.clone() );}}
+
+        // Compute pruning scores.
+        let pruning_scores: Vec<u16> = Self::compute_pruning_scores( netuid, &incentive, &stake );
+        if debug { if_std! { println!( "P:\n{:?}\n", pruning_scores.clone() );}}
 
+        // Sink the results to storage.
+        for i in 0..n {
+            let uid_i: u16 = i as u16;
+            Self::set_ranks( netuid, uid_i, Self::fixed_proportion_to_u16( ranks[ i ] ) );
+            Self::set_trust( netuid, uid_i, Self::fixed_proportion_to_u16( trust[ i ] ) );
+            Self::set_consensus( netuid, uid_i, Self::fixed_proportion_to_u16( consensus[ i ] ) );
+            Self::set_incentives( netuid, uid_i, Self::fixed_proportion_to_u16( incentive[ i ] ) );
+            Self::set_dividends( netuid, uid_i, Self::fixed_proportion_to_u16( dividends[ i ] ) );
+            Self::set_emission( netuid, uid_i, emission[ i ] );
+            Self::set_bonds_row( netuid, uid_i, &ema_bonds[ i ] );
+            Self::set_prunning_score( netuid, uid_i, pruning_scores[ i ] );
+        }
+
+        // Credit the emission to each neuron's stake.
+        Self::distribute_emission( netuid, &emission );
+    }
+
+    // Computes the pruning score of each uid as the average of its incentive and normalized stake,
+    // weighted by 1 / IncentivePruningDenominator and 1 / StakePruningDenominator respectively.
+    // A denominator of zero drops that term, and stake below StakePruningMin counts as zero.
+    pub fn compute_pruning_scores( netuid: u16, incentive: &Vec<I32F32>, stake: &Vec<I32F32> ) -> Vec<u16> {
+        let zero: I32F32 = I32F32::from_num( 0.0 );
+        let one: I32F32 = I32F32::from_num( 1.0 );
+        let incentive_denominator: u16 = Self::get_incentive_pruning_denominator( netuid );
+        let stake_denominator: u16 = Self::get_stake_pruning_denominator( netuid );
+        let stake_pruning_min: u64 = Self::get_stake_pruning_min( netuid ) as u64;
+        let incentive_factor: I32F32 = if incentive_denominator == 0 { zero } else { one / I32F32::from_num( incentive_denominator ) };
+        let stake_factor: I32F32 = if stake_denominator == 0 { zero } else { one / I32F32::from_num( stake_denominator ) };
+        let total_factor: I32F32 = incentive_factor + stake_factor;
+        let mut pruning_scores: Vec<u16> = vec![ 0; incentive.len() ];
+        if total_factor == zero { return pruning_scores }
+        for i in 0..incentive.len() {
+            let mut stake_i: I32F32 = stake[ i ];
+            if Self::get_neuron_stake_for_subnetwork( netuid, i as u16 ) < stake_pruning_min { stake_i = zero; }
+            let score: I32F32 = ( incentive_factor * incentive[ i ] + stake_factor * stake_i ) / total_factor;
+            pruning_scores[ i ] = Self::fixed_proportion_to_u16( score.min( one ) );
+        }
+        pruning_scores
     }
 
     pub fn sum( x: &Vec<I32F32> ) -> I32F32 {
@@ -87,13 +160,27 @@ impl<T: Config> Pallet<T> {
         }
     }
 
+    // Column sums weighted by x, i.e. r_j = SUM(i) w_ij * x_i.
     pub fn matmul( w: &Vec<Vec<I32F32>>, x: &Vec<I32F32> ) -> Vec<I32F32> {
         if w.len() == 0 { return vec![] }
         if w[0].len() == 0 { return vec![] }
-        let mut result: Vec<I32F32> = vec![ I32F32::from_num( 0.0 ); x.len() ];
+        let mut result: Vec<I32F32> = vec![ I32F32::from_num( 0.0 ); w[0].len() ];
+        for (i, w_row) in w.iter().enumerate() {
+            for (j, w_ij) in w_row.iter().enumerate() {
+                result [ j ] += x[ i ] * w_ij 
+            }
+        }
+        result
+    }
+
+    // Row sums weighted by x, i.e. r_i = SUM(j) w_ij * x_j.
+    pub fn matmul_transpose( w: &Vec<Vec<I32F32>>, x: &Vec<I32F32> ) -> Vec<I32F32> {
+        if w.len() == 0 { return vec![] }
+        if w[0].len() == 0 { return vec![] }
+        let mut result: Vec<I32F32> = vec![ I32F32::from_num( 0.0 ); w.len() ];
         for (i, w_row) in w.iter().enumerate() {
-            for (j, x_i) in x.iter().enumerate() {
-                result [ i ] += x_i * w_row [ j ] 
+            for (j, w_ij) in w_row.iter().enumerate() {
+                result [ i ] += x[ j ] * w_ij 
             }
         }
         result
@@ -168,33 +255,118 @@ impl<T: Config> Pallet<T> {
     pub fn vec_u16_proportions_to_fixed( vec: Vec<u16> ) -> Vec<I32F32> { vec.into_iter().map(|e| Self::u16_proportion_to_fixed(e) ).collect() }
     pub fn vec_fixed_proportions_to_u16( vec: Vec<I32F32> ) -> Vec<u16> { vec.into_iter().map(|e| Self::fixed_proportion_to_u16(e) ).collect() }
 
+    // Rho is stored as the integer steepness of the consensus sigmoid.
+    pub fn get_float_rho( netuid: u16 ) -> I32F32 { I32F32::from_num( Self::get_rho( netuid ) ) }
+
+    // Kappa is stored as a proportion of u16::MAX, the trust at which consensus is one half.
+    pub fn get_float_kappa( netuid: u16 ) -> I32F32 { Self::u16_proportion_to_fixed( Self::get_kappa( netuid ) ) }
+
+    // The trust threshold is stored as a proportion of u16::MAX.
+    pub fn get_float_trust_threshold( netuid: u16 ) -> I32F32 { Self::u16_proportion_to_fixed( Self::get_trust_threshold( netuid ) ) }
+
+    // The bonds moving average is stored as the parts per million of the previous bonds kept,
+    // alpha is the remaining share given to the new weights.
+    pub fn get_float_bonds_alpha( netuid: u16 ) -> I32F32 {
+        let bonds_moving_average: u64 = Self::get_bonds_moving_average( netuid ).min( 1_000_000 );
+        I32F32::from_num( 1.0 ) - I32F32::from_num( bonds_moving_average ) / I32F32::from_num( 1_000_000 )
+    }
+
     pub fn set_ranks( netuid:u16, neuron_uid: u16, ranks:u16 ) { Rank::<T>::insert( netuid, neuron_uid, ranks) }
     pub fn set_trust( netuid:u16, neuron_uid:u16, trust:u16) { Trust::<T>::insert( netuid, neuron_uid, trust ) }
     pub fn set_consensus( netuid:u16, neuron_uid:u16, consensus:u16) { Consensus::<T>::insert( netuid, neuron_uid, consensus ) }
     pub fn set_incentives( netuid:u16, neuron_uid:u16, incentive:u16) { Incentive::<T>::insert( netuid, neuron_uid, incentive ) }
     pub fn set_dividends( netuid:u16, neuron_uid:u16, dividends:u16) { Dividends::<T>::insert( netuid, neuron_uid, dividends ) }
+    pub fn set_emission( netuid:u16, neuron_uid:u16, emission:u64) { Emission::<T>::insert( netuid, neuron_uid, emission ) }
 
     pub fn get_ranks( netuid:u16, neuron_uid: u16) -> u16 {   Rank::<T>::get( netuid,  neuron_uid) }
     pub fn get_trust( netuid:u16, neuron_uid: u16 ) -> u16 { Trust::<T>::get( netuid, neuron_uid )  }
     pub fn get_consensus( netuid:u16, neuron_uid: u16 ) -> u16 { Consensus::<T>::get( netuid, neuron_uid )  }
     pub fn get_incentives( netuid:u16, neuron_uid: u16 ) -> u16 { Incentive::<T>::get( netuid, neuron_uid )   }
     pub fn get_dividends( netuid:u16, neuron_uid: u16 ) -> u16 { Dividends::<T>::get( netuid, neuron_uid )  }
+    pub fn get_emission( netuid:u16, neuron_uid: u16 ) -> u64 { Emission::<T>::get( netuid, neuron_uid )  }
+
+    // Returns true for each uid which set weights, or registered, within ActivityCutoff blocks.
+    pub fn compute_active( netuid: u16 ) -> Vec<bool> {
+        let n: usize = Self::get_subnetwork_n( netuid ) as usize;
+        let current_block: u64 = Self::get_current_block_as_u64();
+        let activity_cutoff: u64 = Self::get_activity_cutoff( netuid ) as u64;
+        let mut active: Vec<bool> = vec![ false; n ];
+        for ( uid_i, last_update_i ) in < LastUpdate<T> as IterableStorageDoubleMap<u16, u16, u64> >::iter_prefix( netuid ) {
+            if ( uid_i as usize ) < n { active[ uid_i as usize ] = last_update_i.saturating_add( activity_cutoff ) >= current_block; }
+        }
+        active
+    }
+
+    // Returns true for the k largest elements of x, ties are broken towards the lower index.
+    pub fn is_topk( x: &Vec<I32F32>, k: usize ) -> Vec<bool> {
+        let mut result: Vec<bool> = vec![ false; x.len() ];
+        let mut idxs: Vec<usize> = ( 0..x.len() ).collect();
+        idxs.sort_by( |a, b| x[ *b ].cmp( &x[ *a ] ) ); // Stable sort, descending.
+        for i in idxs.into_iter().take( k ) { result[ i ] = true; }
+        result
+    }
+
+    // Zeroes the elements of x where the mask is false.
+    pub fn inplace_mask_vector( mask: &Vec<bool>, x: &mut Vec<I32F32> ) {
+        let zero: I32F32 = I32F32::from_num( 0.0 );
+        for i in 0..x.len() {
+            if !mask[ i ] { x[ i ] = zero; }
+        }
+    }
+
+    // Zeroes the rows of x where the mask is false.
+    pub fn inplace_mask_rows( mask: &Vec<bool>, x: &mut Vec<Vec<I32F32>> ) {
+        let zero: I32F32 = I32F32::from_num( 0.0 );
+        for i in 0..x.len() {
+            if !mask[ i ] { x[ i ] = vec![ zero; x[ i ].len() ]; }
+        }
+    }
+
+    // Records a replaced uid, the bonds other neurons hold in it are cleared at the next epoch.
+    pub fn add_uid_to_prune( netuid: u16, uid: u16 ) {
+        NeuronsToPruneAtNextEpoch::<T>::mutate( netuid, |uids| if !uids.contains( &uid ) { uids.push( uid ) } );
+    }
+
+    // Removes the bond columns of the passed uids from every bonds row on the network.
+    pub fn clear_bonds_in_uids( netuid: u16, uids: &Vec<u16> ) {
+        let rows: Vec<(u16, Vec<(u16, u16)>)> = < Bonds<T> as IterableStorageDoubleMap<u16, u16, Vec<(u16, u16)> >>::iter_prefix( netuid ).collect();
+        for ( uid_i, bonds_i ) in rows {
+            if !bonds_i.iter().any( |( uid_j, _ )| uids.contains( uid_j ) ) { continue }
+            let kept: Vec<(u16, u16)> = bonds_i.into_iter().filter( |( uid_j, _ )| !uids.contains( uid_j ) ).collect();
+            Bonds::<T>::insert( netuid, uid_i, kept );
+        }
+    }
+
+    // Stores the non-zero entries of a dense bonds row as u16 proportions.
+    pub fn set_bonds_row( netuid:u16, neuron_uid: u16, bonds_row: &Vec<I32F32> ) {
+        let mut sparse_row: Vec<(u16, u16)> = vec![];
+        for ( uid_j, bonds_ij ) in bonds_row.iter().enumerate() {
+            let value: u16 = Self::fixed_proportion_to_u16( *bonds_ij );
+            if value > 0 { sparse_row.push( ( uid_j as u16, value ) ); }
+        }
+        Bonds::<T>::insert( netuid, neuron_uid, sparse_row );
+    }
 
+    // Returns the network stake as a normalized vector. Normalization is done in wider
+    // precision since raw stake does not fit in I32F32.
     pub fn get_stake( netuid:u16 ) -> Vec<I32F32> {
         let n: usize = Self::get_subnetwork_n( netuid ) as usize; 
-        let mut stake: Vec<I32F32> = vec![ I32F32::from_num( 0.0 ); n ]; 
-        for ( uid_i, _ ) in <Keys<T> as IterableStorageDoubleMap<u16, u16, T::AccountId>>::iter_prefix( netuid ){ 
-            stake [ uid_i as usize ] = I32F32::from_num( 0.0 );
+        let mut stake_u64: Vec<u64> = vec![ 0; n ]; 
+        for ( uid_i, stake_i ) in <S<T> as IterableStorageDoubleMap<u16, u16, u64>>::iter_prefix( netuid ){ 
+            if ( uid_i as usize ) < n { stake_u64 [ uid_i as usize ] = stake_i; }
         }
-        stake
+        let total_stake: u128 = stake_u64.iter().map( |si| *si as u128 ).sum();
+        if total_stake == 0 { return vec![ I32F32::from_num( 0.0 ); n ] }
+        stake_u64.iter().map( |si| I32F32::from_num( I96F32::from_num( *si ) / I96F32::from_num( total_stake ) ) ).collect()
     }
 
     pub fn get_weights_sparse( netuid:u16 ) -> Vec<Vec<(u16, I32F32)>> { 
         let n: usize = Self::get_subnetwork_n( netuid ) as usize; 
         let mut weights: Vec<Vec<(u16, I32F32)>> = vec![ vec![]; n ]; 
         for ( uid_i, weights_i ) in < Weights<T> as IterableStorageDoubleMap<u16, u16, Vec<(u16, u16)> >>::iter_prefix( netuid ) {
+            if ( uid_i as usize ) >= n { continue }
             for (uid_j, weight_ij) in weights_i.iter() { 
-                weights [ uid_i as usize ].push( ( *uid_j, Self::u16_proportion_to_fixed( *weight_ij ) ));
+                if ( *uid_j as usize ) < n { weights [ uid_i as usize ].push( ( *uid_j, Self::u16_proportion_to_fixed( *weight_ij ) )); }
             }
         }
         weights
@@ -204,8 +376,9 @@ impl<T: Config> Pallet<T> {
         let n: usize = Self::get_subnetwork_n( netuid ) as usize; 
         let mut weights: Vec<Vec<I32F32>> = vec![ vec![ I32F32::from_num(0.0); n ]; n ]; 
         for ( uid_i, weights_i ) in < Weights<T> as IterableStorageDoubleMap<u16, u16, Vec<(u16, u16)> >>::iter_prefix( netuid ) {
+            if ( uid_i as usize ) >= n { continue }
             for (uid_j, weight_ij) in weights_i.iter() { 
-                weights [ uid_i as usize ] [ *uid_j as usize ] = Self::u16_proportion_to_fixed(  *weight_ij );
+                if ( *uid_j as usize ) < n { weights [ uid_i as usize ] [ *uid_j as usize ] = Self::u16_proportion_to_fixed(  *weight_ij ); }
             }
         }
         weights
@@ -215,8 +388,9 @@ impl<T: Config> Pallet<T> {
         let n: usize = Self::get_subnetwork_n( netuid ) as usize; 
         let mut bonds: Vec<Vec<(u16, I32F32)>> = vec![ vec![]; n ]; 
         for ( uid_i, bonds_i ) in < Bonds<T> as IterableStorageDoubleMap<u16, u16, Vec<(u16, u16)> >>::iter_prefix( netuid ) {
+            if ( uid_i as usize ) >= n { continue }
             for (uid_j, bonds_ij) in bonds_i.iter() { 
-                bonds [ uid_i as usize ].push( ( *uid_j, Self::u16_proportion_to_fixed( *bonds_ij ) ));
+                if ( *uid_j as usize ) < n { bonds [ uid_i as usize ].push( ( *uid_j, Self::u16_proportion_to_fixed( *bonds_ij ) )); }
             }
         }
         bonds
@@ -226,8 +400,9 @@ impl<T: Config> Pallet<T> {
         let n: usize = Self::get_subnetwork_n( netuid ) as usize; 
         let mut bonds: Vec<Vec<I32F32>> = vec![ vec![ I32F32::from_num(0.0); n ]; n ]; 
         for ( uid_i, bonds_i ) in < Bonds<T> as IterableStorageDoubleMap<u16, u16, Vec<(u16, u16)> >>::iter_prefix( netuid ) {
+            if ( uid_i as usize ) >= n { continue }
             for (uid_j, bonds_ij) in bonds_i.iter() { 
-                bonds [ uid_i as usize ] [ *uid_j as usize ] = Self::u16_proportion_to_fixed( *bonds_ij );
+                if ( *uid_j as usize ) < n { bonds [ uid_i as usize ] [ *uid_j as usize ] = Self::u16_proportion_to_fixed( *bonds_ij ); }
             }
         }
         bonds
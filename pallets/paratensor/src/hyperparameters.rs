@@ -0,0 +1,221 @@
+use super::*;
+use frame_support::RuntimeDebug;
+use frame_support::traits::Get;
+use frame_system::{ensure_root};
+use codec::{Decode, Encode};
+use scale_info::TypeInfo;
+
+/// ---- The hyperparameters of a single subnetwork, read and written as one bundle.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct SubnetHyperparams {
+    pub tempo: u16,
+    pub difficulty: u64,
+    pub adjustment_interval: u16,
+    pub target_registrations_per_interval: u16,
+    pub max_registrations_per_block: u16,
+    pub activity_cutoff: u16,
+    pub rho: u16,
+    pub kappa: u16,
+    pub bonds_moving_average: u64,
+    pub trust_threshold: u16,
+    pub max_allowed_validators: u16,
+    pub max_allowed_uids: u16,
+    pub min_allowed_weights: u16,
+    pub max_allowed_max_min_ratio: u16,
+    pub validator_batch_size: u16,
+    pub validator_sequence_length: u16,
+    pub validator_epoch_length: u16,
+    pub validator_epochs_per_reset: u16,
+    pub incentive_pruning_denominator: u16,
+    pub stake_pruning_denominator: u16,
+    pub stake_pruning_min: u16,
+    pub immunity_period: u16,
+}
+
+/// ---- The inclusive (min, max) range accepted by the sudo setter of each hyperparameter.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct HyperparameterRanges {
+    pub blocks_per_step: (u64, u64),
+    pub unbonding_period: (u64, u64),
+    pub difficulty: (u64, u64),
+    pub tempo: (u16, u16),
+    pub adjustment_interval: (u16, u16),
+    pub target_registrations_per_interval: (u16, u16),
+    pub max_registrations_per_block: (u16, u16),
+    pub activity_cutoff: (u16, u16),
+    pub rho: (u16, u16),
+    pub kappa: (u16, u16),
+    pub bonds_moving_average: (u64, u64),
+    pub trust_threshold: (u16, u16),
+    pub max_allowed_validators: (u16, u16),
+    pub max_allowed_uids: (u16, u16),
+    pub min_allowed_weights: (u16, u16),
+    pub max_allowed_max_min_ratio: (u16, u16),
+    pub validator_batch_size: (u16, u16),
+    pub validator_sequence_length: (u16, u16),
+    pub validator_epoch_length: (u16, u16),
+    pub validator_epochs_per_reset: (u16, u16),
+    pub incentive_pruning_denominator: (u16, u16),
+    pub stake_pruning_denominator: (u16, u16),
+    pub stake_pruning_min: (u16, u16),
+    pub immunity_period: (u16, u16),
+}
+
+impl<T: Config> Pallet<T> {
+
+    /// ---- The implementation for the extrinsic sudo_set_hyperparams. Every value is checked
+    /// against its bounds before any is written, so the bundle is applied entirely or not at all.
+    ///
+    /// # Args:
+    /// 	* 'origin': (<T as frame_system::Config>Origin):
+    /// 		- Must be sudo.
+    ///
+    /// 	* 'netuid' (u16):
+    /// 		- The network to set the hyperparameters on.
+    ///
+    /// 	* 'params' (SubnetHyperparams):
+    /// 		- The new hyperparameters of the network.
+    ///
+    /// # Event:
+    /// 	* HyperparamsSet;
+    /// 		- On successfully setting the hyperparameters.
+    ///
+    /// # Raises:
+    /// 	* 'NetworkDoesNotExist':
+    /// 		- The network has not been added.
+    ///
+    /// 	* '<Hyperparameter>OutOfRange':
+    /// 		- A value is outside the bounds of its hyperparameter.
+    ///
+    /// 	* 'MaxAllowedUidsLessThanCurrentUids':
+    /// 		- The max allowed uids is below the number of uids already on the network.
+    ///
+    pub fn do_set_hyperparams( origin: T::Origin, netuid: u16, params: SubnetHyperparams ) -> dispatch::DispatchResult {
+
+        // --- 1. Ensure this is a sudo caller.
+        ensure_root( origin )?;
+
+        // --- 2. Ensure the network exists.
+        ensure!( Self::if_subnet_exist( netuid ), Error::<T>::NetworkDoesNotExist );
+
+        // --- 3. Check every value against its bounds.
+        Self::check_subnet_hyperparams( netuid, &params )?;
+
+        // --- 4. Sink the hyperparameters to storage.
+        Tempo::<T>::insert( netuid, params.tempo );
+        Difficulty::<T>::insert( netuid, params.difficulty );
+        AdjustmentInterval::<T>::insert( netuid, params.adjustment_interval );
+        TargetRegistrationsPerInterval::<T>::insert( netuid, params.target_registrations_per_interval );
+        MaxRegistrationsPerBlock::<T>::insert( netuid, params.max_registrations_per_block );
+        ActivityCutoff::<T>::insert( netuid, params.activity_cutoff );
+        Rho::<T>::insert( netuid, params.rho );
+        Kappa::<T>::insert( netuid, params.kappa );
+        BondsMovingAverage::<T>::insert( netuid, params.bonds_moving_average );
+        TrustThreshold::<T>::insert( netuid, params.trust_threshold );
+        MaxAllowedValidators::<T>::insert( netuid, params.max_allowed_validators );
+        MaxAllowedUids::<T>::insert( netuid, params.max_allowed_uids );
+        MinAllowedWeights::<T>::insert( netuid, params.min_allowed_weights );
+        MaxAllowedMaxMinRatio::<T>::insert( netuid, params.max_allowed_max_min_ratio );
+        ValidatorBatchSize::<T>::insert( netuid, params.validator_batch_size );
+        ValidatorSequenceLength::<T>::insert( netuid, params.validator_sequence_length );
+        ValidatorEpochLen::<T>::insert( netuid, params.validator_epoch_length );
+        ValidatorEpochsPerReset::<T>::insert( netuid, params.validator_epochs_per_reset );
+        IncentivePruningDenominator::<T>::insert( netuid, params.incentive_pruning_denominator );
+        StakePruningDenominator::<T>::insert( netuid, params.stake_pruning_denominator );
+        StakePruningMin::<T>::insert( netuid, params.stake_pruning_min );
+        ImmunityPeriod::<T>::insert( netuid, params.immunity_period );
+
+        // --- 5. Emit the tracking event.
+        Self::deposit_event( Event::HyperparamsSet( netuid ) );
+
+        // --- 6. Return ok.
+        Ok(())
+    }
+
+    // --- Returns an error naming the first hyperparameter which is outside its bounds on the network.
+    pub fn check_subnet_hyperparams( netuid: u16, params: &SubnetHyperparams ) -> dispatch::DispatchResult {
+        ensure!( params.tempo >= T::MinimumTempo::get() && params.tempo <= T::MaximumTempo::get(), Error::<T>::TempoOutOfRange );
+        ensure!( params.difficulty >= T::MinimumDifficulty::get() && params.difficulty <= T::MaximumDifficulty::get(), Error::<T>::DifficultyOutOfRange );
+        ensure!( params.adjustment_interval >= T::MinimumAdjustmentInterval::get() && params.adjustment_interval <= T::MaximumAdjustmentInterval::get(), Error::<T>::AdjustmentIntervalOutOfRange );
+        ensure!( params.target_registrations_per_interval >= T::MinimumTargetRegistrationsPerInterval::get() && params.target_registrations_per_interval <= T::MaximumTargetRegistrationsPerInterval::get(), Error::<T>::TargetRegistrationsPerIntervalOutOfRange );
+        ensure!( params.max_registrations_per_block >= T::MinimumMaxRegistrationsPerBlock::get() && params.max_registrations_per_block <= T::MaximumMaxRegistrationsPerBlock::get(), Error::<T>::MaxRegistrationsPerBlockOutOfRange );
+        ensure!( params.activity_cutoff >= T::MinimumActivityCutoff::get() && params.activity_cutoff <= T::MaximumActivityCutoff::get(), Error::<T>::ActivityCutoffOutOfRange );
+        ensure!( params.rho >= T::MinimumRho::get() && params.rho <= T::MaximumRho::get(), Error::<T>::RhoOutOfRange );
+        ensure!( params.kappa >= T::MinimumKappa::get() && params.kappa <= T::MaximumKappa::get(), Error::<T>::KappaOutOfRange );
+        ensure!( params.bonds_moving_average >= T::MinimumBondsMovingAverage::get() && params.bonds_moving_average <= T::MaximumBondsMovingAverage::get(), Error::<T>::BondsMovingAverageOutOfRange );
+        ensure!( params.trust_threshold >= T::MinimumTrustThreshold::get() && params.trust_threshold <= T::MaximumTrustThreshold::get(), Error::<T>::TrustThresholdOutOfRange );
+        ensure!( params.max_allowed_validators >= T::MinimumMaxAllowedValidators::get() && params.max_allowed_validators <= T::MaximumMaxAllowedValidators::get(), Error::<T>::MaxAllowedValidatorsOutOfRange );
+        ensure!( params.max_allowed_uids >= T::MinimumMaxAllowedUids::get() && params.max_allowed_uids <= T::MaximumMaxAllowedUids::get(), Error::<T>::MaxAllowedUidsOutOfRange );
+        ensure!( params.max_allowed_uids >= SubnetworkN::<T>::get( netuid ), Error::<T>::MaxAllowedUidsLessThanCurrentUids );
+        ensure!( params.min_allowed_weights >= T::MinimumMinAllowedWeights::get() && params.min_allowed_weights <= T::MaximumMinAllowedWeights::get(), Error::<T>::MinAllowedWeightsOutOfRange );
+        ensure!( params.max_allowed_max_min_ratio >= T::MinimumMaxAllowedMaxMinRatio::get() && params.max_allowed_max_min_ratio <= T::MaximumMaxAllowedMaxMinRatio::get(), Error::<T>::MaxAllowedMaxMinRatioOutOfRange );
+        ensure!( params.validator_batch_size >= T::MinimumValidatorBatchSize::get() && params.validator_batch_size <= T::MaximumValidatorBatchSize::get(), Error::<T>::ValidatorBatchSizeOutOfRange );
+        ensure!( params.validator_sequence_length >= T::MinimumValidatorSequenceLength::get() && params.validator_sequence_length <= T::MaximumValidatorSequenceLength::get(), Error::<T>::ValidatorSequenceLengthOutOfRange );
+        ensure!( params.validator_epoch_length >= T::MinimumValidatorEpochLength::get() && params.validator_epoch_length <= T::MaximumValidatorEpochLength::get(), Error::<T>::ValidatorEpochLengthOutOfRange );
+        ensure!( params.validator_epochs_per_reset >= T::MinimumValidatorEpochsPerReset::get() && params.validator_epochs_per_reset <= T::MaximumValidatorEpochsPerReset::get(), Error::<T>::ValidatorEpochsPerResetOutOfRange );
+        ensure!( params.incentive_pruning_denominator >= T::MinimumIncentivePruningDenominator::get() && params.incentive_pruning_denominator <= T::MaximumIncentivePruningDenominator::get(), Error::<T>::IncentivePruningDenominatorOutOfRange );
+        ensure!( params.stake_pruning_denominator >= T::MinimumStakePruningDenominator::get() && params.stake_pruning_denominator <= T::MaximumStakePruningDenominator::get(), Error::<T>::StakePruningDenominatorOutOfRange );
+        ensure!( params.stake_pruning_min >= T::MinimumStakePruningMin::get() && params.stake_pruning_min <= T::MaximumStakePruningMin::get(), Error::<T>::StakePruningMinOutOfRange );
+        ensure!( params.immunity_period >= T::MinimumImmunityPeriod::get() && params.immunity_period <= T::MaximumImmunityPeriod::get(), Error::<T>::ImmunityPeriodOutOfRange );
+        Ok(())
+    }
+
+    /// ---- Returns the hyperparameters of the network, or None if the network has not been added.
+    pub fn get_subnet_hyperparams( netuid: u16 ) -> Option<SubnetHyperparams> {
+        if !Self::if_subnet_exist( netuid ) { return None }
+        Some( SubnetHyperparams {
+            tempo: Tempo::<T>::get( netuid ),
+            difficulty: Difficulty::<T>::get( netuid ),
+            adjustment_interval: AdjustmentInterval::<T>::get( netuid ),
+            target_registrations_per_interval: TargetRegistrationsPerInterval::<T>::get( netuid ),
+            max_registrations_per_block: MaxRegistrationsPerBlock::<T>::get( netuid ),
+            activity_cutoff: ActivityCutoff::<T>::get( netuid ),
+            rho: Rho::<T>::get( netuid ),
+            kappa: Kappa::<T>::get( netuid ),
+            bonds_moving_average: BondsMovingAverage::<T>::get( netuid ),
+            trust_threshold: TrustThreshold::<T>::get( netuid ),
+            max_allowed_validators: MaxAllowedValidators::<T>::get( netuid ),
+            max_allowed_uids: MaxAllowedUids::<T>::get( netuid ),
+            min_allowed_weights: MinAllowedWeights::<T>::get( netuid ),
+            max_allowed_max_min_ratio: MaxAllowedMaxMinRatio::<T>::get( netuid ),
+            validator_batch_size: ValidatorBatchSize::<T>::get( netuid ),
+            validator_sequence_length: ValidatorSequenceLength::<T>::get( netuid ),
+            validator_epoch_length: ValidatorEpochLen::<T>::get( netuid ),
+            validator_epochs_per_reset: ValidatorEpochsPerReset::<T>::get( netuid ),
+            incentive_pruning_denominator: IncentivePruningDenominator::<T>::get( netuid ),
+            stake_pruning_denominator: StakePruningDenominator::<T>::get( netuid ),
+            stake_pruning_min: StakePruningMin::<T>::get( netuid ),
+            immunity_period: ImmunityPeriod::<T>::get( netuid ),
+        } )
+    }
+
+    /// ---- Returns the bounds each hyperparameter is checked against when set by sudo.
+    pub fn get_hyperparameter_ranges() -> HyperparameterRanges {
+        HyperparameterRanges {
+            blocks_per_step: ( T::MinimumBlocksPerStep::get(), T::MaximumBlocksPerStep::get() ),
+            unbonding_period: ( T::MinimumUnbondingPeriod::get(), T::MaximumUnbondingPeriod::get() ),
+            difficulty: ( T::MinimumDifficulty::get(), T::MaximumDifficulty::get() ),
+            tempo: ( T::MinimumTempo::get(), T::MaximumTempo::get() ),
+            adjustment_interval: ( T::MinimumAdjustmentInterval::get(), T::MaximumAdjustmentInterval::get() ),
+            target_registrations_per_interval: ( T::MinimumTargetRegistrationsPerInterval::get(), T::MaximumTargetRegistrationsPerInterval::get() ),
+            max_registrations_per_block: ( T::MinimumMaxRegistrationsPerBlock::get(), T::MaximumMaxRegistrationsPerBlock::get() ),
+            activity_cutoff: ( T::MinimumActivityCutoff::get(), T::MaximumActivityCutoff::get() ),
+            rho: ( T::MinimumRho::get(), T::MaximumRho::get() ),
+            kappa: ( T::MinimumKappa::get(), T::MaximumKappa::get() ),
+            bonds_moving_average: ( T::MinimumBondsMovingAverage::get(), T::MaximumBondsMovingAverage::get() ),
+            trust_threshold: ( T::MinimumTrustThreshold::get(), T::MaximumTrustThreshold::get() ),
+            max_allowed_validators: ( T::MinimumMaxAllowedValidators::get(), T::MaximumMaxAllowedValidators::get() ),
+            max_allowed_uids: ( T::MinimumMaxAllowedUids::get(), T::MaximumMaxAllowedUids::get() ),
+            min_allowed_weights: ( T::MinimumMinAllowedWeights::get(), T::MaximumMinAllowedWeights::get() ),
+            max_allowed_max_min_ratio: ( T::MinimumMaxAllowedMaxMinRatio::get(), T::MaximumMaxAllowedMaxMinRatio::get() ),
+            validator_batch_size: ( T::MinimumValidatorBatchSize::get(), T::MaximumValidatorBatchSize::get() ),
+            validator_sequence_length: ( T::MinimumValidatorSequenceLength::get(), T::MaximumValidatorSequenceLength::get() ),
+            validator_epoch_length: ( T::MinimumValidatorEpochLength::get(), T::MaximumValidatorEpochLength::get() ),
+            validator_epochs_per_reset: ( T::MinimumValidatorEpochsPerReset::get(), T::MaximumValidatorEpochsPerReset::get() ),
+            incentive_pruning_denominator: ( T::MinimumIncentivePruningDenominator::get(), T::MaximumIncentivePruningDenominator::get() ),
+            stake_pruning_denominator: ( T::MinimumStakePruningDenominator::get(), T::MaximumStakePruningDenominator::get() ),
+            stake_pruning_min: ( T::MinimumStakePruningMin::get(), T::MaximumStakePruningMin::get() ),
+            immunity_period: ( T::MinimumImmunityPeriod::get(), T::MaximumImmunityPeriod::get() ),
+        }
+    }
+}
@@ -7,7 +7,8 @@ use frame_system::{
 
 use frame_support::{dispatch, ensure, traits::{
 	Currency, 
-	ExistenceRequirement,
+	LockableCurrency,
+	LockIdentifier,
 	tokens::{
 		WithdrawReasons
 	}
@@ -21,17 +22,31 @@ mod benchmarking;
 ///	-Paratensor-Imports
 /// ************************************************************
 mod registration;
+mod block_step;
 mod epoch;
 mod utils;
 mod staking;
+mod weights;
+mod migration;
+mod neuron_info;
+mod networks;
+mod serving;
+mod hyperparameters;
+mod unbonding;
+
+pub use neuron_info::NeuronInfo;
+pub use hyperparameters::{HyperparameterRanges, SubnetHyperparams};
+pub use unbonding::UnbondingChunk;
 
 #[frame_support::pallet]
 pub mod pallet {
 	use frame_support::pallet_prelude::*;
 	use frame_system::pallet_prelude::*;
-	use frame_support::traits::Currency;
+	use frame_support::traits::{Currency, LockableCurrency};
 	use frame_support::inherent::Vec;
 	use frame_support::sp_std::vec;
+	use crate::SubnetHyperparams;
+	use crate::UnbondingChunk;
 
 	/// ================
 	/// ==== Config ====
@@ -41,8 +56,8 @@ pub mod pallet {
 		/// --- Because this pallet emits events, it depends on the runtime's definition of an event.
 		type Event: From<Event<Self>> + IsType<<Self as frame_system::Config>::Event>;
 
-		/// --- Currency type that will be used to place deposits on neurons
-		type Currency: Currency<Self::AccountId> + Send + Sync;
+		/// --- Currency type that will be used to place deposits on neurons and lock stake in coldkey accounts
+		type Currency: Currency<Self::AccountId> + LockableCurrency<Self::AccountId, Moment = Self::BlockNumber> + Send + Sync;
 
 		/// --- Initialization
 		#[pallet::constant]
@@ -69,6 +84,14 @@ pub mod pallet {
 		#[pallet::constant]
 		type InitialDifficulty: Get<u64>;
 
+		/// Minimum difficulty reached by the difficulty adjustment.
+		#[pallet::constant]
+		type MinimumDifficulty: Get<u64>;
+
+		/// Maximum difficulty reached by the difficulty adjustment.
+		#[pallet::constant]
+		type MaximumDifficulty: Get<u64>;
+
 		/// Initial adjustment interval.
 		#[pallet::constant]
 		type InitialAdjustmentInterval: Get<u16>;
@@ -85,6 +108,30 @@ pub mod pallet {
 		#[pallet::constant]
 		type InitialKappa: Get<u16>;
 
+		/// Initial bonds moving average.
+		#[pallet::constant]
+		type InitialBondsMovingAverage: Get<u64>;
+
+		/// Initial trust threshold.
+		#[pallet::constant]
+		type InitialTrustThreshold: Get<u16>;
+
+		/// Initial max allowed validators.
+		#[pallet::constant]
+		type InitialMaxAllowedValidators: Get<u16>;
+
+		/// Initial delegate take, the proportion of u16::MAX of a delegate's emission kept by its owner.
+		#[pallet::constant]
+		type InitialDelegateTake: Get<u16>;
+
+		/// Initial number of blocks removed stake stays unbonding before it can be withdrawn.
+		#[pallet::constant]
+		type InitialUnbondingPeriod: Get<u64>;
+
+		/// Maximum number of chunks a coldkey can have unbonding at once.
+		#[pallet::constant]
+		type MaxUnbondingChunks: Get<u32>;
+
 		/// Max UID constant.
 		#[pallet::constant]
 		type InitialMaxAllowedUids: Get<u16>;
@@ -132,16 +179,249 @@ pub mod pallet {
 		// Initial prunning score for each neuron
 		#[pallet::constant]
 		type InitialPrunningScore: Get<u16>;		
+
+		/// Initial number of blocks a hotkey must wait between serving calls.
+		#[pallet::constant]
+		type InitialServingRateLimit: Get<u64>;
+
+		/// --- Hyperparameter bounds, enforced by the sudo setters.
+		/// Minimum blocks per step.
+		#[pallet::constant]
+		type MinimumBlocksPerStep: Get<u64>;
+
+		/// Maximum blocks per step.
+		#[pallet::constant]
+		type MaximumBlocksPerStep: Get<u64>;
+
+		/// Minimum tempo.
+		#[pallet::constant]
+		type MinimumTempo: Get<u16>;
+
+		/// Maximum tempo.
+		#[pallet::constant]
+		type MaximumTempo: Get<u16>;
+
+		/// Minimum adjustment interval.
+		#[pallet::constant]
+		type MinimumAdjustmentInterval: Get<u16>;
+
+		/// Maximum adjustment interval.
+		#[pallet::constant]
+		type MaximumAdjustmentInterval: Get<u16>;
+
+		/// Minimum target registrations per interval.
+		#[pallet::constant]
+		type MinimumTargetRegistrationsPerInterval: Get<u16>;
+
+		/// Maximum target registrations per interval.
+		#[pallet::constant]
+		type MaximumTargetRegistrationsPerInterval: Get<u16>;
+
+		/// Minimum max registrations per block.
+		#[pallet::constant]
+		type MinimumMaxRegistrationsPerBlock: Get<u16>;
+
+		/// Maximum max registrations per block.
+		#[pallet::constant]
+		type MaximumMaxRegistrationsPerBlock: Get<u16>;
+
+		/// Minimum activity cutoff.
+		#[pallet::constant]
+		type MinimumActivityCutoff: Get<u16>;
+
+		/// Maximum activity cutoff.
+		#[pallet::constant]
+		type MaximumActivityCutoff: Get<u16>;
+
+		/// Minimum rho.
+		#[pallet::constant]
+		type MinimumRho: Get<u16>;
+
+		/// Maximum rho.
+		#[pallet::constant]
+		type MaximumRho: Get<u16>;
+
+		/// Minimum kappa.
+		#[pallet::constant]
+		type MinimumKappa: Get<u16>;
+
+		/// Maximum kappa.
+		#[pallet::constant]
+		type MaximumKappa: Get<u16>;
+
+		/// Minimum max allowed uids.
+		#[pallet::constant]
+		type MinimumMaxAllowedUids: Get<u16>;
+
+		/// Maximum max allowed uids.
+		#[pallet::constant]
+		type MaximumMaxAllowedUids: Get<u16>;
+
+		/// Minimum min allowed weights.
+		#[pallet::constant]
+		type MinimumMinAllowedWeights: Get<u16>;
+
+		/// Maximum min allowed weights.
+		#[pallet::constant]
+		type MaximumMinAllowedWeights: Get<u16>;
+
+		/// Minimum max allowed max min ratio.
+		#[pallet::constant]
+		type MinimumMaxAllowedMaxMinRatio: Get<u16>;
+
+		/// Maximum max allowed max min ratio.
+		#[pallet::constant]
+		type MaximumMaxAllowedMaxMinRatio: Get<u16>;
+
+		/// Minimum validator batch size.
+		#[pallet::constant]
+		type MinimumValidatorBatchSize: Get<u16>;
+
+		/// Maximum validator batch size.
+		#[pallet::constant]
+		type MaximumValidatorBatchSize: Get<u16>;
+
+		/// Minimum validator sequence length.
+		#[pallet::constant]
+		type MinimumValidatorSequenceLength: Get<u16>;
+
+		/// Maximum validator sequence length.
+		#[pallet::constant]
+		type MaximumValidatorSequenceLength: Get<u16>;
+
+		/// Minimum validator epoch length.
+		#[pallet::constant]
+		type MinimumValidatorEpochLength: Get<u16>;
+
+		/// Maximum validator epoch length.
+		#[pallet::constant]
+		type MaximumValidatorEpochLength: Get<u16>;
+
+		/// Minimum validator epochs per reset.
+		#[pallet::constant]
+		type MinimumValidatorEpochsPerReset: Get<u16>;
+
+		/// Maximum validator epochs per reset.
+		#[pallet::constant]
+		type MaximumValidatorEpochsPerReset: Get<u16>;
+
+		/// Minimum incentive pruning denominator.
+		#[pallet::constant]
+		type MinimumIncentivePruningDenominator: Get<u16>;
+
+		/// Maximum incentive pruning denominator.
+		#[pallet::constant]
+		type MaximumIncentivePruningDenominator: Get<u16>;
+
+		/// Minimum stake pruning denominator.
+		#[pallet::constant]
+		type MinimumStakePruningDenominator: Get<u16>;
+
+		/// Maximum stake pruning denominator.
+		#[pallet::constant]
+		type MaximumStakePruningDenominator: Get<u16>;
+
+		/// Minimum stake pruning min.
+		#[pallet::constant]
+		type MinimumStakePruningMin: Get<u16>;
+
+		/// Maximum stake pruning min.
+		#[pallet::constant]
+		type MaximumStakePruningMin: Get<u16>;
+
+		/// Minimum immunity period.
+		#[pallet::constant]
+		type MinimumImmunityPeriod: Get<u16>;
+
+		/// Maximum immunity period.
+		#[pallet::constant]
+		type MaximumImmunityPeriod: Get<u16>;
+
+		/// Minimum bonds moving average.
+		#[pallet::constant]
+		type MinimumBondsMovingAverage: Get<u64>;
+
+		/// Maximum bonds moving average.
+		#[pallet::constant]
+		type MaximumBondsMovingAverage: Get<u64>;
+
+		/// Minimum trust threshold.
+		#[pallet::constant]
+		type MinimumTrustThreshold: Get<u16>;
+
+		/// Maximum trust threshold.
+		#[pallet::constant]
+		type MaximumTrustThreshold: Get<u16>;
+
+		/// Minimum max allowed validators.
+		#[pallet::constant]
+		type MinimumMaxAllowedValidators: Get<u16>;
+
+		/// Maximum max allowed validators.
+		#[pallet::constant]
+		type MaximumMaxAllowedValidators: Get<u16>;
+
+		/// Minimum delegate take.
+		#[pallet::constant]
+		type MinimumDelegateTake: Get<u16>;
+
+		/// Maximum delegate take.
+		#[pallet::constant]
+		type MaximumDelegateTake: Get<u16>;
+
+		/// Minimum unbonding period.
+		#[pallet::constant]
+		type MinimumUnbondingPeriod: Get<u64>;
+
+		/// Maximum unbonding period.
+		#[pallet::constant]
+		type MaximumUnbondingPeriod: Get<u64>;
 	}
 
 	pub type AccountIdOf<T> = <T as frame_system::Config>::AccountId;
 	//pub type NeuronMetadataOf<T> = NeuronMetadata<AccountIdOf<T>>;
 
+	/// ---- The endpoint a neuron serves its axon on.
+	#[derive(Encode, Decode, Default, TypeInfo, Clone, PartialEq, Eq, RuntimeDebug)]
+	pub struct AxonInfo {
+		/// ---- Block at which the axon was last served.
+		pub block: u64,
+		/// ---- Axon version.
+		pub version: u32,
+		/// ---- Axon u128 encoded ip address of type v6 or v4.
+		pub ip: u128,
+		/// ---- Axon u16 encoded port.
+		pub port: u16,
+		/// ---- Axon ip type, 4 for ipv4 and 6 for ipv6.
+		pub ip_type: u8,
+		/// ---- Axon modality, TEXT: 0, IMAGE: 1, TENSOR: 2.
+		pub modality: u8,
+	}
+
+	/// ---- The endpoint a neuron serves its prometheus metrics on.
+	#[derive(Encode, Decode, Default, TypeInfo, Clone, PartialEq, Eq, RuntimeDebug)]
+	pub struct PrometheusInfo {
+		/// ---- Block at which the prometheus endpoint was last served.
+		pub block: u64,
+		/// ---- Prometheus version.
+		pub version: u32,
+		/// ---- Prometheus u128 encoded ip address of type v6 or v4.
+		pub ip: u128,
+		/// ---- Prometheus u16 encoded port.
+		pub port: u16,
+		/// ---- Prometheus ip type, 4 for ipv4 and 6 for ipv6.
+		pub ip_type: u8,
+	}
+
 	/// ===============================
 	/// ==== Global Params Storage ====
 	/// ===============================
+	/// ---- The current storage version, bumped by each storage migration.
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(12);
+
 	#[pallet::pallet]
 	#[pallet::generate_store(pub(super) trait Store)]
+	#[pallet::storage_version(STORAGE_VERSION)]
 	#[pallet::without_storage_info]
 	pub struct Pallet<T>(_);
 
@@ -182,31 +462,33 @@ pub mod pallet {
 	#[pallet::type_value]
 	pub fn DefaultAdjustmentInterval<T: Config>() -> u16 { T::InitialAdjustmentInterval::get() }
 	#[pallet::storage]
-	pub type AdjustmentInterval<T> = StorageValue<_, u16, ValueQuery, DefaultAdjustmentInterval<T> >;
+	pub type AdjustmentInterval<T> = StorageMap<_, Identity, u16, u16, ValueQuery, DefaultAdjustmentInterval<T> >;
 
 	#[pallet::type_value] 
 	pub fn DefaultTargetRegistrationsPerInterval<T: Config>() -> u16 { T::InitialTargetRegistrationsPerInterval::get() }
 	#[pallet::storage]
-	pub type TargetRegistrationsPerInterval<T> = StorageValue<_, u16, ValueQuery, DefaultTargetRegistrationsPerInterval<T> >;
+	pub type TargetRegistrationsPerInterval<T> = StorageMap<_, Identity, u16, u16, ValueQuery, DefaultTargetRegistrationsPerInterval<T> >;
 
-	/// ---- StorageItem Global Max Registration Per Block
+	/// ---- SingleMap Network UID --> Max Registration Per Block
 	#[pallet::type_value] 
 	pub fn DefaultMaxRegistrationsPerBlock<T: Config>() -> u16 { T::InitialMaxRegistrationsPerBlock::get() }
 	#[pallet::storage]
-	pub type MaxRegistrationsPerBlock<T> = StorageValue<_, u16, ValueQuery, DefaultMaxRegistrationsPerBlock<T> >;
+	pub type MaxRegistrationsPerBlock<T> = StorageMap<_, Identity, u16, u16, ValueQuery, DefaultMaxRegistrationsPerBlock<T> >;
 
+	/// ---- SingleMap Network UID --> Registrations This Block, cleared at the start of each block.
 	#[pallet::storage]
-	pub type RegistrationsThisBlock<T> = StorageValue<_, u16, ValueQuery>;
+	pub type RegistrationsThisBlock<T> = StorageMap<_, Identity, u16, u16, ValueQuery>;
 
 	#[pallet::storage]
 	#[pallet::getter(fn usedwork)]
     pub(super) type UsedWork<T:Config> = StorageMap<_, Identity, Vec<u8>, u64, ValueQuery>;
 
+	/// ---- DoubleMap Network UID --> Neuron UID --> Block At Registration
 	#[pallet::type_value] 
 	pub fn DefaultBlockAtRegistration<T: Config>() -> u64 { 0 }
 	#[pallet::storage]
 	#[pallet::getter(fn block_at_registration)]
-    pub(super) type BlockAtRegistration<T:Config> = StorageMap<_, Identity, u16, u64, ValueQuery, DefaultBlockAtRegistration<T> >;
+    pub(super) type BlockAtRegistration<T:Config> = StorageDoubleMap<_, Identity, u16, Identity, u16, u64, ValueQuery, DefaultBlockAtRegistration<T> >;
 
 	#[pallet::type_value] 
 	pub fn DefaultBlocksSinceLastStep<T: Config>() -> u64 { 0 }
@@ -215,6 +497,14 @@ pub mod pallet {
 
 	#[pallet::storage]
 	pub type LastMechansimStepBlock<T> = StorageValue<_, u64, ValueQuery>;
+
+	/// ---- SingleMap Network UID --> Emission accumulated since the last epoch
+	#[pallet::storage]
+	pub type PendingEmission<T> = StorageMap<_, Identity, u16, u64, ValueQuery>;
+
+	/// ---- SingleMap Network UID --> Steps since the last epoch
+	#[pallet::storage]
+	pub type StepsSinceLastEpoch<T> = StorageMap<_, Identity, u16, u64, ValueQuery>;
 	/// ==============================
 	/// ==== Accounts Storage ====
 	/// ==============================
@@ -223,17 +513,17 @@ pub mod pallet {
 	#[pallet::storage]
     pub(super) type Stake<T:Config> = StorageMap<_, Identity, T::AccountId, u64, ValueQuery>;
 
-	/// ---- SingleMap Hotkey --> Coldkey
+	/// ---- SingleMap Hotkey --> Coldkey which owns the hotkey
 	#[pallet::type_value] 
-	pub fn DefaultHotkeyAccount<T: Config>() -> T::AccountId { T::AccountId::decode(&mut sp_runtime::traits::TrailingZeroInput::zeroes()).unwrap()}
+	pub fn DefaultOwner<T: Config>() -> T::AccountId { T::AccountId::decode(&mut sp_runtime::traits::TrailingZeroInput::zeroes()).unwrap()}
 	#[pallet::storage]
-    pub(super) type Coldkeys<T:Config> = StorageMap<_, Blake2_128Concat, T::AccountId, T::AccountId, ValueQuery, DefaultHotkeyAccount<T> >;
+	pub(super) type Owner<T:Config> = StorageMap<_, Blake2_128Concat, T::AccountId, T::AccountId, ValueQuery, DefaultOwner<T> >;
 
-	/// ---- SingleMap Coldkey --> Hotkey
+	/// ---- SingleMap Coldkey --> Hotkeys owned by the coldkey
 	#[pallet::type_value] 
-	pub fn DefaultColdkeyAccount<T: Config>() -> T::AccountId { T::AccountId::decode(&mut sp_runtime::traits::TrailingZeroInput::zeroes()).unwrap()}
+	pub fn DefaultOwnedHotkeys<T: Config>() -> Vec<T::AccountId> { vec![] }
 	#[pallet::storage]
-	pub(super) type Hotkeys<T:Config> = StorageMap<_, Blake2_128Concat, T::AccountId, T::AccountId, ValueQuery, DefaultColdkeyAccount<T> >;
+	pub(super) type OwnedHotkeys<T:Config> = StorageMap<_, Blake2_128Concat, T::AccountId, Vec<T::AccountId>, ValueQuery, DefaultOwnedHotkeys<T> >;
 
 	/// --- SingleMap Hotkey --> Network UID // a list of subnets that each hotkey is registered on
 	#[pallet::type_value] 
@@ -241,6 +531,42 @@ pub mod pallet {
 	#[pallet::storage]
 	pub(super) type Subnets<T:Config> = StorageMap<_, Blake2_128Concat, T::AccountId, Vec<u16>, ValueQuery, DefaultHotkeys<T> >;
 
+	/// ---- DoubleMap Hotkey --> Coldkey --> Stake held by the coldkey on the hotkey
+	#[pallet::storage]
+	pub(super) type ColdkeyStake<T:Config> = StorageDoubleMap<_, Blake2_128Concat, T::AccountId, Blake2_128Concat, T::AccountId, u64, ValueQuery>;
+
+	/// ---- SingleMap Hotkey --> Take of a delegate hotkey, which accepts stake from any coldkey
+	#[pallet::type_value] 
+	pub fn DefaultDelegateTake<T: Config>() -> u16 { T::InitialDelegateTake::get() }
+	#[pallet::storage]
+	pub(super) type Delegates<T:Config> = StorageMap<_, Blake2_128Concat, T::AccountId, u16, ValueQuery, DefaultDelegateTake<T> >;
+
+	/// ---- SingleMap Hotkey --> Sum of the stake accounted to coldkeys, the shares emission is split over
+	#[pallet::storage]
+	pub(super) type DelegatedStake<T:Config> = StorageMap<_, Blake2_128Concat, T::AccountId, u64, ValueQuery>;
+
+	/// ---- SingleMap Hotkey --> Emission accumulated per share of delegated stake, scaled by REWARD_PER_SHARE_SCALE
+	#[pallet::storage]
+	pub(super) type RewardPerShare<T:Config> = StorageMap<_, Blake2_128Concat, T::AccountId, u128, ValueQuery>;
+
+	/// ---- DoubleMap Hotkey --> Coldkey --> Reward per share already credited to the coldkey's stake
+	#[pallet::storage]
+	pub(super) type RewardDebt<T:Config> = StorageDoubleMap<_, Blake2_128Concat, T::AccountId, Blake2_128Concat, T::AccountId, u128, ValueQuery>;
+
+	/// ---- StorageItem Number of blocks removed stake stays unbonding
+	#[pallet::type_value] 
+	pub fn DefaultUnbondingPeriod<T: Config>() -> u64 { T::InitialUnbondingPeriod::get() }
+	#[pallet::storage]
+	pub type UnbondingPeriod<T> = StorageValue<_, u64, ValueQuery, DefaultUnbondingPeriod<T>>;
+
+	/// ---- SingleMap Coldkey --> Chunks of removed stake waiting to be withdrawn
+	#[pallet::storage]
+	pub(super) type Unbonding<T:Config> = StorageMap<_, Blake2_128Concat, T::AccountId, Vec<UnbondingChunk<T::AccountId>>, ValueQuery>;
+
+	/// ---- SingleMap Coldkey --> Balance held under the stake lock, the coldkey's stake and unbonding chunks
+	#[pallet::storage]
+	pub(super) type LockedStake<T:Config> = StorageMap<_, Blake2_128Concat, T::AccountId, u64, ValueQuery>;
+
 	/// =======================================
 	/// ==== Subnetwork Hyperparam stroage  ====
 	/// =======================================
@@ -281,6 +607,24 @@ pub mod pallet {
 	#[pallet::storage]
 	pub type Kappa<T> = StorageMap<_, Identity, u16, u16, ValueQuery, DefaultKappa<T> >;
 
+	/// --- SingleMap Network UID ---> Bonds Moving Average
+	#[pallet::type_value]
+	pub fn DefaultBondsMovingAverage<T: Config>() -> u64 {T::InitialBondsMovingAverage::get()}
+	#[pallet::storage]
+	pub type BondsMovingAverage<T> = StorageMap<_, Identity, u16, u64, ValueQuery, DefaultBondsMovingAverage<T> >;
+
+	/// --- SingleMap Network UID ---> Trust Threshold
+	#[pallet::type_value]
+	pub fn DefaultTrustThreshold<T: Config>() -> u16 {T::InitialTrustThreshold::get()}
+	#[pallet::storage]
+	pub type TrustThreshold<T> = StorageMap<_, Identity, u16, u16, ValueQuery, DefaultTrustThreshold<T> >;
+
+	/// --- SingleMap Network UID ---> Max Allowed Validators
+	#[pallet::type_value]
+	pub fn DefaultMaxAllowedValidators<T: Config>() -> u16 {T::InitialMaxAllowedValidators::get()}
+	#[pallet::storage]
+	pub type MaxAllowedValidators<T> = StorageMap<_, Identity, u16, u16, ValueQuery, DefaultMaxAllowedValidators<T> >;
+
 	/// --- SingleMap Network UID ---> Max Allowed Uids
 	#[pallet::type_value] 
 	pub fn DefaultMaxAllowedUids<T: Config>() -> u16 { T::InitialMaxAllowedUids::get() }
@@ -341,15 +685,50 @@ pub mod pallet {
 	#[pallet::storage]
 	pub type ActivityCutoff<T> = StorageMap<_, Identity, u16, u16, ValueQuery, DefaultActivityCutoff<T> >;
 
-	/// ---- SingleMap Network UID --> Neuron UID, we use to record uids to prune at next epoch.
+	/// ---- SingleMap Network UID --> Neuron UIDs replaced since the last epoch. The next epoch
+	/// clears the bonds other neurons hold in these uids.
 	#[pallet::storage]
-	#[pallet::getter(fn uid_to_prune)]
-    pub(super) type NeuronsToPruneAtNextEpoch<T:Config> = StorageMap<_, Identity, u16, u16, ValueQuery>;
+	#[pallet::getter(fn uids_to_prune)]
+    pub(super) type NeuronsToPruneAtNextEpoch<T:Config> = StorageMap<_, Identity, u16, Vec<u16>, ValueQuery>;
 
 	// ---- SingleMap Network UID --> Registration This Interval
 	#[pallet::storage]
 	pub type RegistrationsThisInterval<T:Config> = StorageMap<_, Identity, u16, u16, ValueQuery>;
 
+	/// ---- SingleMap Network UID --> If network is added.
+	#[pallet::type_value]
+	pub fn DefaultNetworksAdded<T: Config>() ->  bool { false }
+	#[pallet::storage]
+	pub(super) type NetworksAdded<T:Config> = StorageMap<_, Identity, u16, bool, ValueQuery, DefaultNetworksAdded<T>>;
+
+	/// ---- SingleMap Network UID --> Network Modality	TEXT: 0, IMAGE: 1, TENSOR: 2
+	#[pallet::type_value]
+	pub fn DefaultModality<T: Config>() -> u16 { 0 }
+	#[pallet::storage]
+	pub(super) type NetworkModality<T:Config> = StorageMap<_, Identity, u16, u16, ValueQuery, DefaultModality<T>>;
+
+	/// ---- StorageItem Serving Rate Limit
+	#[pallet::type_value]
+	pub fn DefaultServingRateLimit<T: Config>() -> u64 { T::InitialServingRateLimit::get() }
+	#[pallet::storage]
+	pub type ServingRateLimit<T> = StorageValue<_, u64, ValueQuery, DefaultServingRateLimit<T>>;
+
+	/// ---- DoubleMap Network UID --> Hotkey --> Axon Info
+	#[pallet::storage]
+	pub(super) type Axons<T:Config> = StorageDoubleMap<_, Identity, u16, Blake2_128Concat, T::AccountId, AxonInfo, OptionQuery>;
+
+	/// ---- DoubleMap Network UID --> Hotkey --> Prometheus Info
+	#[pallet::storage]
+	pub(super) type Prometheus<T:Config> = StorageDoubleMap<_, Identity, u16, Blake2_128Concat, T::AccountId, PrometheusInfo, OptionQuery>;
+
+	/// ---- Map Hotkey --> Block the hotkey last served an axon on any network
+	#[pallet::storage]
+	pub(super) type LastServedAxon<T:Config> = StorageMap<_, Blake2_128Concat, T::AccountId, u64, OptionQuery>;
+
+	/// ---- Map Hotkey --> Block the hotkey last served a prometheus endpoint on any network
+	#[pallet::storage]
+	pub(super) type LastServedPrometheus<T:Config> = StorageMap<_, Blake2_128Concat, T::AccountId, u64, OptionQuery>;
+
 	/// =======================================
 	/// ==== Subnetwork Consensus Storage  ====
 	/// =======================================
@@ -389,6 +768,18 @@ pub mod pallet {
 	#[pallet::storage]
 	pub(super) type Active<T:Config> = StorageMap< _, Identity, u16, Vec<bool>, ValueQuery, DefaultActive<T> >;
 
+	/// ---- SingleMap Network UID --> Network Validator Permit Vector
+	#[pallet::type_value] 
+	pub fn DefaultValidatorPermit<T:Config>() -> Vec<bool> { vec![] }
+	#[pallet::storage]
+	pub(super) type ValidatorPermit<T:Config> = StorageMap< _, Identity, u16, Vec<bool>, ValueQuery, DefaultValidatorPermit<T> >;
+
+	/// ---- DoubleMap Network UID --> Neuron UID --> Block of the last weights update
+	#[pallet::type_value] 
+	pub fn DefaultLastUpdate<T:Config>() -> u64 { 0 }
+	#[pallet::storage]
+	pub(super) type LastUpdate<T:Config> = StorageDoubleMap<_, Identity, u16, Identity, u16, u64, ValueQuery, DefaultLastUpdate<T> >;
+
 	/// ---- DoubleMap Network UID --> Neuron UID --> Neuron Stake
 	#[pallet::type_value] 
 	pub fn DefaultStake<T:Config>() -> u64 {0 }
@@ -457,15 +848,98 @@ pub mod pallet {
 		/// the hotkey staking account onto the coldkey account.
 		StakeRemoved(T::AccountId, u64),
 
+		/// --- Event created when a hotkey is made a delegate by its coldkey, (coldkey, hotkey).
+		DelegateAdded(T::AccountId, T::AccountId),
+
+		/// --- Event created when the take of a delegate hotkey is set, (hotkey, take).
+		DelegateTakeSet(T::AccountId, u16),
+
+		/// --- Event created when removed stake enters the unbonding queue, (coldkey, hotkey, amount, unlock_block).
+		StakeUnbonding(T::AccountId, T::AccountId, u64, u64),
+
+		/// --- Event created when unlocked unbonding chunks are withdrawn to the coldkey, (coldkey, amount).
+		UnbondedWithdrawn(T::AccountId, u64),
+
+		/// ---- Event created when the unbonding period has been set.
+		UnbondingPeriodSet(u64),
+
 		/// ---- Event created when a caller successfully set's their weights on a subnetwork.
 		WeightsSet(u16, u16),
 
 		/// ---- Event created when default blocks per step has been set.
 		BlocksPerStepSet(u64),
 
-		/// ---- Event created when Tempo is set
-		TempoSet(u16),
-		/* TO DO: more events for setting hyper parameters should be defined here */
+		/// ---- Event created when the tempo is set for a subnetwork.
+		TempoSet(u16, u16),
+
+		/// ---- Event created when the emission ratio is set for a subnetwork.
+		EmissionRatioSet(u16, u16),
+
+		/// ---- Event created when the registration difficulty is set for a subnetwork.
+		DifficultySet(u16, u64),
+
+		/// ---- Event created when the difficulty adjustment interval is set for a subnetwork.
+		AdjustmentIntervalSet(u16, u16),
+
+		/// ---- Event created when the target registrations per interval is set for a subnetwork.
+		TargetRegistrationsPerIntervalSet(u16, u16),
+
+		/// ---- Event created when the max registrations per block is set for a subnetwork.
+		MaxRegistrationsPerBlockSet(u16, u16),
+
+		/// ---- Event created when the activity cutoff is set for a subnetwork.
+		ActivityCutoffSet(u16, u16),
+
+		/// ---- Event created when rho is set for a subnetwork.
+		RhoSet(u16, u16),
+
+		/// ---- Event created when kappa is set for a subnetwork.
+		KappaSet(u16, u16),
+
+		/// ---- Event created when the min allowed weights is set for a subnetwork.
+		MinAllowedWeightSet(u16, u16),
+
+		/// ---- Event created when the max allowed max min ratio is set for a subnetwork.
+		MaxAllowedMaxMinRatioSet(u16, u16),
+
+		/// ---- Event created when the validator batch size is set for a subnetwork.
+		ValidatorBatchSizeSet(u16, u16),
+
+		/// ---- Event created when the validator sequence length is set for a subnetwork.
+		ValidatorSequenceLengthSet(u16, u16),
+
+		/// ---- Event created when the validator epoch length is set for a subnetwork.
+		ValidatorEpochLengthSet(u16, u16),
+
+		/// ---- Event created when the validator epochs per reset is set for a subnetwork.
+		ValidatorEpochsPerResetSet(u16, u16),
+
+		/// ---- Event created when the incentive pruning denominator is set for a subnetwork.
+		IncentivePruningDenominatorSet(u16, u16),
+
+		/// ---- Event created when the stake pruning denominator is set for a subnetwork.
+		StakePruningDenominatorSet(u16, u16),
+
+		/// ---- Event created when the stake pruning min is set for a subnetwork.
+		StakePruningMinSet(u16, u16),
+
+		/// ---- Event created when the immunity period is set for a subnetwork.
+		ImmunityPeriodSet(u16, u16),
+
+		/// ---- Event created when the bonds moving average is set for a subnetwork.
+		BondsMovingAverageSet(u16, u64),
+
+		/// ---- Event created when the trust threshold is set for a subnetwork.
+		TrustThresholdSet(u16, u16),
+
+		/// ---- Event created when the max allowed validators is set for a subnetwork.
+		MaxAllowedValidatorsSet(u16, u16),
+
+		/// ---- Event created when the full hyperparameter bundle is set for a subnetwork.
+		HyperparamsSet(u16),
+
+		/// ---- Event created when all bonds on a subnetwork are reset.
+		BondsReset(u16),
 
 		/// --- Event created when a new neuron account has been registered to 
 		/// the chain.
@@ -476,6 +950,18 @@ pub mod pallet {
 
 		/// --- Event created when total stake increased
 		TotalStakeIncreased(u64),
+
+		/// ---- Event created when a new network is added.
+		NetworkAdded(u16, u16),
+
+		/// ---- Event created when a network is removed.
+		NetworkRemoved(u16),
+
+		/// ---- Event created when a neuron serves or updates its axon on a network.
+		AxonServed(u16, T::AccountId),
+
+		/// ---- Event created when a neuron serves or updates its prometheus endpoint on a network.
+		PrometheusServed(u16, T::AccountId),
 	}
 	
 	/// ================
@@ -509,7 +995,7 @@ pub mod pallet {
 		/// ---- Thrown when the caller tries to add stake, but for some reason the requested
 		/// amount could not be withdrawn from the coldkey account
 		BalanceWithdrawalError,
-		
+
 		/// ---- Thrown when the caller attempts to set the weight keys
 		/// and values but these vectors have different size.
 		WeightVecNotEqualSize,
@@ -530,10 +1016,6 @@ pub mod pallet {
 		/// max value is more than MaxAllowedMaxMinRatio.
 		MaxAllowedMaxMinRatioExceeded,
 
-		// --- Error for setting blocksPerStep
-		
-		// --- Error for setting Tempo /* TO DO: more Errors for different hyper parameters should be defined here */
-
 		/// ---- Thrown when registrations this block exceeds allowed number.
 		ToManyRegistrationsThisBlock,
 
@@ -553,7 +1035,8 @@ pub mod pallet {
 		/// ---- Thrown if the supplied pow hash seal does not match the supplied work.
 		InvalidSeal,
 
-		/// ---  Thrown if the vaule is invalid for MaxAllowedUids
+		/// ---- Deprecated, replaced by MaxAllowedUidsOutOfRange. Kept so that the
+		/// index of every error after it does not change.
 		MaxAllowedUIdsNotAllowed,
 
 		/// ---- Thrown when the dispatch attempts to convert between a u64 and T::balance 
@@ -563,6 +1046,126 @@ pub mod pallet {
 		/// --- thrown when the caller requests adding stake for a hotkey to the 
 		/// total stake which already added
 		StakeAlreadyAdded,
+
+		/// ---- Thrown when become_delegate is called on a hotkey which is already a delegate.
+		AlreadyDelegate,
+
+		/// ---- Thrown when set_delegate_take is called on a hotkey which is not a delegate.
+		NotADelegate,
+
+		/// ---- Thrown when the delegate take is outside [MinimumDelegateTake, MaximumDelegateTake].
+		DelegateTakeOutOfRange,
+
+		/// ---- Thrown when withdraw_unbonded is called and no unbonding chunk has unlocked.
+		NothingToWithdraw,
+
+		/// ---- Thrown when remove_stake would exceed MaxUnbondingChunks in the caller's unbonding queue.
+		TooManyUnbondingChunks,
+
+		/// ---- Thrown when the unbonding period is outside [MinimumUnbondingPeriod, MaximumUnbondingPeriod].
+		UnbondingPeriodOutOfRange,
+
+		/// ---- Thrown when the caller requests removing zero stake. See: fn remove_stake.
+		StakeToWithdrawIsZero,
+
+		/// ---- Thrown when the blocks per step is outside [MinimumBlocksPerStep, MaximumBlocksPerStep].
+		BlocksPerStepOutOfRange,
+
+		/// ---- Thrown when the tempo is outside [MinimumTempo, MaximumTempo].
+		TempoOutOfRange,
+
+		/// ---- Thrown when the adjustment interval is outside [MinimumAdjustmentInterval, MaximumAdjustmentInterval].
+		AdjustmentIntervalOutOfRange,
+
+		/// ---- Thrown when the target registrations per interval is outside [MinimumTargetRegistrationsPerInterval, MaximumTargetRegistrationsPerInterval].
+		TargetRegistrationsPerIntervalOutOfRange,
+
+		/// ---- Thrown when the max registrations per block is outside [MinimumMaxRegistrationsPerBlock, MaximumMaxRegistrationsPerBlock].
+		MaxRegistrationsPerBlockOutOfRange,
+
+		/// ---- Thrown when the activity cutoff is outside [MinimumActivityCutoff, MaximumActivityCutoff].
+		ActivityCutoffOutOfRange,
+
+		/// ---- Thrown when the rho is outside [MinimumRho, MaximumRho].
+		RhoOutOfRange,
+
+		/// ---- Thrown when the kappa is outside [MinimumKappa, MaximumKappa].
+		KappaOutOfRange,
+
+		/// ---- Thrown when the max allowed uids is outside [MinimumMaxAllowedUids, MaximumMaxAllowedUids].
+		MaxAllowedUidsOutOfRange,
+
+		/// ---- Thrown when the max allowed uids is set below the number of uids already on the network.
+		MaxAllowedUidsLessThanCurrentUids,
+
+		/// ---- Thrown when the min allowed weights is outside [MinimumMinAllowedWeights, MaximumMinAllowedWeights].
+		MinAllowedWeightsOutOfRange,
+
+		/// ---- Thrown when the max allowed max min ratio is outside [MinimumMaxAllowedMaxMinRatio, MaximumMaxAllowedMaxMinRatio].
+		MaxAllowedMaxMinRatioOutOfRange,
+
+		/// ---- Thrown when the validator batch size is outside [MinimumValidatorBatchSize, MaximumValidatorBatchSize].
+		ValidatorBatchSizeOutOfRange,
+
+		/// ---- Thrown when the validator sequence length is outside [MinimumValidatorSequenceLength, MaximumValidatorSequenceLength].
+		ValidatorSequenceLengthOutOfRange,
+
+		/// ---- Thrown when the validator epoch length is outside [MinimumValidatorEpochLength, MaximumValidatorEpochLength].
+		ValidatorEpochLengthOutOfRange,
+
+		/// ---- Thrown when the validator epochs per reset is outside [MinimumValidatorEpochsPerReset, MaximumValidatorEpochsPerReset].
+		ValidatorEpochsPerResetOutOfRange,
+
+		/// ---- Thrown when the incentive pruning denominator is outside [MinimumIncentivePruningDenominator, MaximumIncentivePruningDenominator].
+		IncentivePruningDenominatorOutOfRange,
+
+		/// ---- Thrown when the stake pruning denominator is outside [MinimumStakePruningDenominator, MaximumStakePruningDenominator].
+		StakePruningDenominatorOutOfRange,
+
+		/// ---- Thrown when the stake pruning min is outside [MinimumStakePruningMin, MaximumStakePruningMin].
+		StakePruningMinOutOfRange,
+
+		/// ---- Thrown when the immunity period is outside [MinimumImmunityPeriod, MaximumImmunityPeriod].
+		ImmunityPeriodOutOfRange,
+
+		/// ---- Thrown when the bonds moving average is outside [MinimumBondsMovingAverage, MaximumBondsMovingAverage].
+		BondsMovingAverageOutOfRange,
+
+		/// ---- Thrown when the trust threshold is outside [MinimumTrustThreshold, MaximumTrustThreshold].
+		TrustThresholdOutOfRange,
+
+		/// ---- Thrown when the max allowed validators is outside [MinimumMaxAllowedValidators, MaximumMaxAllowedValidators].
+		MaxAllowedValidatorsOutOfRange,
+
+		/// ---- Thrown when the difficulty is outside [MinimumDifficulty, MaximumDifficulty].
+		DifficultyOutOfRange,
+
+		/// ---- Thrown when the network is full and every registered neuron
+		/// is still inside its immunity period, so no uid can be pruned.
+		AllNeuronsImmune,
+
+		/// ---- Thrown when setting an emission ratio would push the sum of all
+		/// emission ratios above u16::MAX.
+		InvalidEmissionRatio,
+
+		/// ---- Thrown when the caller requests an operation on a network
+		/// which has not been added.
+		NetworkDoesNotExist,
+
+		/// ---- Thrown when the caller attempts to add a network which already exists.
+		NetworkExist,
+
+		/// ---- Thrown when a network is added or an axon served with a modality other than TEXT: 0, IMAGE: 1 or TENSOR: 2.
+		InvalidModality,
+
+		/// ---- Thrown when the caller attempts to serve with an ip type other than 4 or 6.
+		InvalidIpType,
+
+		/// ---- Thrown when the caller attempts to serve an ip address which is not valid for its type.
+		InvalidIpAddress,
+
+		/// ---- Thrown when a hotkey serves again before ServingRateLimit blocks have passed.
+		ServingRateLimitExceeded,
 	}
 
 	/// ================
@@ -570,12 +1173,34 @@ pub mod pallet {
 	/// ================
 	#[pallet::hooks]
 	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> { 
+		/// ---- Called on runtime upgrade, before on_initialize. Applies the pending storage migrations.
+		fn on_runtime_upgrade() -> Weight {
+			Self::migrate_to_v1_dense_uids()
+				.saturating_add( Self::migrate_to_v2_block_at_registration() )
+				.saturating_add( Self::migrate_to_v3_neurons_to_prune() )
+				.saturating_add( Self::migrate_to_v4_owned_hotkeys() )
+				.saturating_add( Self::migrate_to_v5_coldkey_stake() )
+				.saturating_add( Self::migrate_to_v6_delegate_take() )
+				.saturating_add( Self::migrate_to_v7_stake_locks() )
+				.saturating_add( Self::migrate_to_v8_networks_added() )
+				.saturating_add( Self::migrate_to_v9_registrations_per_block() )
+				.saturating_add( Self::migrate_to_v10_emission_ratios() )
+				.saturating_add( Self::migrate_to_v11_registration_intervals() )
+				.saturating_add( Self::migrate_to_v12_last_update() )
+		}
+
 		/// ---- Called on the initialization of this pallet. (the order of on_finalize calls is determined in the runtime)
 		///
 		/// # Args:
 		/// 	* 'n': (T::BlockNumber):
 		/// 		- The number of the block we are initializing.
 		fn on_initialize( _n: BlockNumberFor<T> ) -> Weight {
+			// Reads and writes of the step counter.
+			let mut weight: Weight = T::DbWeight::get().reads_writes( 2, 1 );
+
+			// Clear the per block registration counters.
+			weight = weight.saturating_add( Self::reset_registrations_this_block() );
+
 			// Only run the block step every `blocks_per_step`.
 			// Initially `blocks_since_last_step + 1` is 0 but increments until it reaches `blocks_per_step`.
 			// We use the >= here in the event that we lower get_blocks per step and these qualities never meet.
@@ -587,7 +1212,7 @@ pub mod pallet {
 				let emission_this_step:u64 = ( Self::get_blocks_since_last_step() + 1 ) * Self::get_block_emission();
 
 				// Apply emission step based on mechanism and updates values.
-				//Self::mechanism_step( emission_this_step ); /* TO DO */
+				weight = weight.saturating_add( Self::mechanism_step( emission_this_step ) );
 
 				// Reset counter down to 0, this ensures that if `blocks_per_step=1` we will do an emission on every block.
 				// If `blocks_per_step=2` we will skip the next block, since (0+1) !>= 2, add one to the counter, and then apply the next
@@ -600,9 +1225,9 @@ pub mod pallet {
 			}
 
 			// Make a difficulty update.
-			// Self::update_difficulty(); /* TO DO */
+			weight = weight.saturating_add( Self::update_difficulty() );
 			
-			return 0;
+			return weight;
 		}
 	}
 
@@ -644,43 +1269,52 @@ pub mod pallet {
 		/// 		- On successfully setting the weights on chain.
 		///
 		/// # Raises:
+		/// 	* 'NetworkDoesNotExist':
+		/// 		- If the network has not been added.
+		///
+		/// 	* 'NotRegistered':
+		/// 		- If the calling hotkey is not registered on the network.
+		///
 		/// 	* 'WeightVecNotEqualSize':
 		/// 		- If the passed weights and uids have unequal size.
 		///
-		/// 	* 'WeightSumToLarge':
-		/// 		- When the calling coldkey is not associated with the hotkey account.
+		/// 	* 'DuplicateUids':
+		/// 		- If the passed uids contain duplicates.
 		///
-		/// 	* 'InsufficientBalance':
-		/// 		- When the amount to stake exceeds the amount of balance in the
-		/// 		associated colkey account.
+		/// 	* 'InvalidUid':
+		/// 		- If a passed uid does not exist on the network.
 		///
-        #[pallet::weight((0, DispatchClass::Normal, Pays::No))]
+		/// 	* 'NotSettingEnoughWeights':
+		/// 		- If fewer than MinAllowedWeights weights are passed.
+		///
+		/// 	* 'MaxAllowedMaxMinRatioExceeded':
+		/// 		- If the normalized max to min weight ratio exceeds MaxAllowedMaxMinRatio.
+		///
+        #[pallet::weight((0, DispatchClass::Normal, Pays::No))]
 		pub fn set_weights(
-			_origin:OriginFor<T>, 
-			_netuid: u16,
-			_dests: Vec<u16>, 
-			_weights: Vec<u16>
+			origin:OriginFor<T>, 
+			netuid: u16,
+			dests: Vec<u16>, 
+			weights: Vec<u16>
 		) -> DispatchResult {
-            Ok(())
-			//Self::do_set_weights(origin, netuid, dests, weights)
+			Self::do_set_weights(origin, netuid, dests, weights)
 		}
 
 		/// --- Adds stake to a hotkey. The call is made from the
-		/// coldkey account linked in the hotkey.
-		/// Only the associated coldkey is allowed to make staking and
-		/// unstaking requests. This protects the neuron against
-		/// attacks on its hotkey running in production code.
+		/// coldkey account linked in the hotkey, or from any coldkey
+		/// when the hotkey is a delegate. The stake is accounted to the
+		/// calling coldkey and only it can remove the stake again.
 		///
 		/// # Args:
 		/// 	* 'origin': (<T as frame_system::Config>Origin):
-		/// 		- The caller, a coldkey signature associated with the hotkey account.
+		/// 		- The caller, the owning coldkey or a nominator of a delegate hotkey.
 		///
 		/// 	* 'hotkey' (T::AccountId):
 		/// 		- The hotkey account to add stake to.
 		///
 		/// 	* 'ammount_staked' (u64):
-		/// 		- The ammount to transfer from the balances account of the cold key
-		/// 		into the staking account of the hotkey.
+		/// 		- The ammount of the cold key's balance to lock as stake on the hotkey.
+		/// 		The balance stays in the cold key account under the stake lock.
 		///
 		/// # Event:
 		/// 	* 'StakeAdded':
@@ -691,11 +1325,11 @@ pub mod pallet {
 		/// 		- If the hotkey account is not active (has not subscribed)
 		///
 		/// 	* 'NonAssociatedColdKey':
-		/// 		- When the calling coldkey is not associated with the hotkey account.
+		/// 		- When the calling coldkey does not own the hotkey and the hotkey is not a delegate.
 		///
-		/// 	* 'InsufficientBalance':
-		/// 		- When the amount to stake exceeds the amount of balance in the
-		/// 		associated colkey account.
+		/// 	* 'NotEnoughBalanceToStake':
+		/// 		- When the amount to stake exceeds the balance in the associated
+		/// 		colkey account which is not yet locked as stake.
 		///
 		#[pallet::weight((0, DispatchClass::Normal, Pays::No))]
 		pub fn add_stake(
@@ -707,31 +1341,40 @@ pub mod pallet {
 		}
 
 		/// ---- Remove stake from the staking account. The call must be made
-		/// from the coldkey account attached to the neuron metadata. Only this key
-		/// has permission to make staking and unstaking requests.
+		/// from a coldkey which holds stake on the hotkey, only the stake
+		/// added by the caller can be removed.
 		///
 		/// # Args:
 		/// 	* 'origin': (<T as frame_system::Config>Origin):
-		/// 		- The caller, a coldkey signature associated with the hotkey account.
+		/// 		- The caller, the owning coldkey or a nominator of a delegate hotkey.
 		///
 		/// 	* 'hotkey' (T::AccountId):
 		/// 		- The hotkey account to withdraw stake from.
 		///
 		/// 	* 'ammount_unstaked' (u64):
-		/// 		- The ammount to transfer from the staking account into the balance
-		/// 		of the coldkey.
+		/// 		- The ammount to move from the staking account into the unbonding
+		/// 		queue of the coldkey, see withdraw_unbonded.
 		///
 		/// # Event:
 		/// 	* 'StakeRemoved':
 		/// 		- On successful withdrawl.
 		///
+		/// 	* 'StakeUnbonding':
+		/// 		- With the block at which the removed stake can be withdrawn.
+		///
 		/// # Raises:
 		/// 	* 'NonAssociatedColdKey':
-		/// 		- When the calling coldkey is not associated with the hotkey account.
+		/// 		- When the calling coldkey does not own the hotkey and the hotkey is not a delegate.
 		///
 		/// 	* 'NotEnoughStaketoWithdraw':
-		/// 		- When the amount to unstake exceeds the quantity staked in the
-		/// 		associated hotkey staking account.
+		/// 		- When the amount to unstake exceeds the stake held by the caller
+		/// 		on the hotkey.
+		///
+		/// 	* 'StakeToWithdrawIsZero':
+		/// 		- When the amount to unstake is zero.
+		///
+		/// 	* 'TooManyUnbondingChunks':
+		/// 		- When the caller already has MaxUnbondingChunks chunks unbonding.
 		///
 		#[pallet::weight((0, DispatchClass::Normal, Pays::No))]
 		pub fn remove_stake(
@@ -742,6 +1385,96 @@ pub mod pallet {
 			Self::do_remove_stake(origin, hotkey, ammount_unstaked)
 		}
 
+		/// ---- Makes a hotkey a delegate, after which any coldkey can nominate stake to it.
+		/// The call must be made from the coldkey which owns the hotkey.
+		///
+		/// # Args:
+		/// 	* 'origin': (<T as frame_system::Config>Origin):
+		/// 		- The caller, the coldkey which owns the hotkey.
+		///
+		/// 	* 'hotkey' (T::AccountId):
+		/// 		- The hotkey to make a delegate.
+		///
+		/// # Event:
+		/// 	* 'DelegateAdded':
+		/// 		- On successfully making the hotkey a delegate.
+		///
+		/// # Raises:
+		/// 	* 'NotRegistered':
+		/// 		- If the hotkey is not registered on any network.
+		///
+		/// 	* 'NonAssociatedColdKey':
+		/// 		- When the calling coldkey does not own the hotkey.
+		///
+		/// 	* 'AlreadyDelegate':
+		/// 		- When the hotkey is already a delegate.
+		///
+		#[pallet::weight((0, DispatchClass::Normal, Pays::No))]
+		pub fn become_delegate(
+			origin: OriginFor<T>, 
+			hotkey: T::AccountId
+		) -> DispatchResult {
+			Self::do_become_delegate(origin, hotkey)
+		}
+
+		/// ---- Sets the take of a delegate hotkey, the proportion of u16::MAX of the hotkey's
+		/// emission kept by its owner before the rest is split over the stake of its nominators.
+		///
+		/// # Args:
+		/// 	* 'origin': (<T as frame_system::Config>Origin):
+		/// 		- The caller, the coldkey which owns the hotkey.
+		///
+		/// 	* 'hotkey' (T::AccountId):
+		/// 		- The delegate hotkey.
+		///
+		/// 	* 'take' (u16):
+		/// 		- The new take.
+		///
+		/// # Event:
+		/// 	* 'DelegateTakeSet':
+		/// 		- On successfully setting the take.
+		///
+		/// # Raises:
+		/// 	* 'NonAssociatedColdKey':
+		/// 		- When the calling coldkey does not own the hotkey.
+		///
+		/// 	* 'NotADelegate':
+		/// 		- When the hotkey is not a delegate.
+		///
+		/// 	* 'DelegateTakeOutOfRange':
+		/// 		- When the take is outside [MinimumDelegateTake, MaximumDelegateTake].
+		///
+		#[pallet::weight((0, DispatchClass::Normal, Pays::No))]
+		pub fn set_delegate_take(
+			origin: OriginFor<T>, 
+			hotkey: T::AccountId,
+			take: u16
+		) -> DispatchResult {
+			Self::do_set_delegate_take(origin, hotkey, take)
+		}
+
+		/// ---- Withdraws every chunk of removed stake in the caller's unbonding queue
+		/// which has passed the unbonding period into the caller's balance.
+		///
+		/// # Args:
+		/// 	* 'origin': (<T as frame_system::Config>Origin):
+		/// 		- The caller, the coldkey which removed the stake.
+		///
+		/// # Event:
+		/// 	* 'UnbondedWithdrawn':
+		/// 		- On successfully withdrawing the unlocked chunks.
+		///
+		/// # Raises:
+		/// 	* 'NothingToWithdraw':
+		/// 		- When no chunk in the caller's queue has unlocked.
+		///
+		#[pallet::weight((0, DispatchClass::Normal, Pays::No))]
+		pub fn withdraw_unbonded(
+			origin: OriginFor<T>
+		) -> DispatchResult {
+			Self::do_withdraw_unbonded(origin)
+		}
+
 		/// ---- Serves or updates axon information for the neuron associated with the caller. If the caller
 		/// already registered the metadata is updated. If the caller is not registered this call throws NotRegsitered.
 		///
@@ -749,6 +1482,12 @@ pub mod pallet {
 		/// 	* 'origin': (<T as frame_system::Config>Origin):
 		/// 		- The caller, a hotkey associated of the registered neuron.
 		///
+		/// 	* 'netuid' (u16):
+		/// 		- The network the axon is served on.
+		///
+		/// 	* 'version' (u32):
+		/// 		- The axon version.
+		///
 		/// 	* 'ip' (u128):
 		/// 		- The u64 encoded IP address of type 6 or 4.
 		///
@@ -763,18 +1502,91 @@ pub mod pallet {
 		///
 		/// # Event:
 		/// 	* 'AxonServed':
-		/// 		- On subscription of a new neuron to the active set.
+		/// 		- On successfully serving the axon.
+		///
+		/// # Raises:
+		/// 	* 'NetworkDoesNotExist':
+		/// 		- If the network has not been added.
+		///
+		/// 	* 'NotRegistered':
+		/// 		- If the calling hotkey is not registered on the network.
+		///
+		/// 	* 'InvalidIpType':
+		/// 		- If the ip type is not 4 or 6.
+		///
+		/// 	* 'InvalidIpAddress':
+		/// 		- If the ip address is not valid for the ip type.
+		///
+		/// 	* 'InvalidModality':
+		/// 		- If the modality is not one of TEXT: 0, IMAGE: 1, TENSOR: 2.
+		///
+		/// 	* 'ServingRateLimitExceeded':
+		/// 		- If the hotkey served an axon on any network within the last ServingRateLimit blocks.
 		///
 		#[pallet::weight((0, DispatchClass::Normal, Pays::No))]
 		pub fn serve_axon (
-			_origin:OriginFor<T>, 
-			_version: u32, 
-			_ip: u128, 
-			_port: u16, 
-			_ip_type: u8, 
-			_modality: u8 
-		) -> DispatchResult {  /*TO DO */
-			Ok(()) 
+			origin:OriginFor<T>, 
+			netuid: u16,
+			version: u32, 
+			ip: u128, 
+			port: u16, 
+			ip_type: u8, 
+			modality: u8 
+		) -> DispatchResult {
+			Self::do_serve_axon( origin, netuid, version, ip, port, ip_type, modality )
+		}
+
+		/// ---- Serves or updates the prometheus endpoint for the neuron associated with the caller.
+		///
+		/// # Args:
+		/// 	* 'origin': (<T as frame_system::Config>Origin):
+		/// 		- The caller, a hotkey associated of the registered neuron.
+		///
+		/// 	* 'netuid' (u16):
+		/// 		- The network the endpoint is served on.
+		///
+		/// 	* 'version' (u32):
+		/// 		- The prometheus version.
+		///
+		/// 	* 'ip' (u128):
+		/// 		- The u128 encoded IP address of type 6 or 4.
+		///
+		/// 	* 'port' (u16):
+		/// 		- The port number where this neuron serves its metrics.
+		///
+		/// 	* 'ip_type' (u8):
+		/// 		- The ip type one of (4,6).
+		///
+		/// # Event:
+		/// 	* 'PrometheusServed':
+		/// 		- On successfully serving the prometheus endpoint.
+		///
+		/// # Raises:
+		/// 	* 'NetworkDoesNotExist':
+		/// 		- If the network has not been added.
+		///
+		/// 	* 'NotRegistered':
+		/// 		- If the calling hotkey is not registered on the network.
+		///
+		/// 	* 'InvalidIpType':
+		/// 		- If the ip type is not 4 or 6.
+		///
+		/// 	* 'InvalidIpAddress':
+		/// 		- If the ip address is not valid for the ip type.
+		///
+		/// 	* 'ServingRateLimitExceeded':
+		/// 		- If the hotkey served a prometheus endpoint on any network within the last ServingRateLimit blocks.
+		///
+		#[pallet::weight((0, DispatchClass::Normal, Pays::No))]
+		pub fn serve_prometheus (
+			origin:OriginFor<T>, 
+			netuid: u16,
+			version: u32, 
+			ip: u128, 
+			port: u16, 
+			ip_type: u8 
+		) -> DispatchResult {
+			Self::do_serve_prometheus( origin, netuid, version, ip, port, ip_type )
 		}
 		/// ---- Registers a new neuron to the subnetwork. 
 		///
@@ -802,6 +1614,10 @@ pub mod pallet {
 		/// 	* 'NeuronRegistered':
 		/// 		- On subscription of a new neuron to the active set.
 		///
+		/// # Raises:
+		/// 	* 'NetworkDoesNotExist':
+		/// 		- If the network has not been added.
+		///
 		#[pallet::weight((0, DispatchClass::Normal, Pays::No))]
 		pub fn register( 
 				origin:OriginFor<T>, 
@@ -816,6 +1632,86 @@ pub mod pallet {
 		}
 
 		/// ---- SUDO ONLY FUNCTIONS ------
+		/// Adds a new network.
+		///
+		/// # Args:
+		/// 	* 'origin': (<T as frame_system::Config>Origin):
+		/// 		- The caller, must be sudo.
+		///
+		/// 	* 'netuid' (u16):
+		/// 		- The uid of the new network.
+		///
+		/// 	* 'tempo' (u16):
+		/// 		- The number of steps between epochs on the network.
+		///
+		/// 	* 'modality' (u16):
+		/// 		- The network modality, one of TEXT: 0, IMAGE: 1, TENSOR: 2.
+		///
+		/// # Event:
+		/// 	* 'NetworkAdded':
+		/// 		- On successfully adding the network.
+		///
+		/// # Raises:
+		/// 	* 'NetworkExist':
+		/// 		- If a network with this netuid has already been added.
+		///
+		/// 	* 'InvalidModality':
+		/// 		- If the modality is not one of the known modalities.
+		///
+		#[pallet::weight((0, DispatchClass::Operational, Pays::No))]
+		pub fn sudo_add_network(
+			origin: OriginFor<T>,
+			netuid: u16,
+			tempo: u16,
+			modality: u16
+		) -> DispatchResult {
+			Self::do_add_network( origin, netuid, tempo, modality )
+		}
+
+		/// ---- Removes a network and every neuron registered on it.
+		///
+		/// # Args:
+		/// 	* 'origin': (<T as frame_system::Config>Origin):
+		/// 		- The caller, must be sudo.
+		///
+		/// 	* 'netuid' (u16):
+		/// 		- The uid of the network to remove.
+		///
+		/// # Event:
+		/// 	* 'NetworkRemoved':
+		/// 		- On successfully removing the network.
+		///
+		/// # Raises:
+		/// 	* 'NetworkDoesNotExist':
+		/// 		- If no network with this netuid has been added.
+		///
+		#[pallet::weight((0, DispatchClass::Operational, Pays::No))]
+		pub fn sudo_remove_network(
+			origin: OriginFor<T>,
+			netuid: u16
+		) -> DispatchResult {
+			Self::do_remove_network( origin, netuid )
+		}
+
+		/// ---- Sets every hyperparameter of a subnetwork at once. The call fails without
+		/// writing anything if any value is out of range.
+		/// # Args:
+		/// 	* 'origin': (<T as frame_system::Config>Origin):
+		/// 		- The caller, must be sudo.
+		/// 	* `netuid` (u16):
+		/// 		- The network to set the hyperparameters on.
+		/// 	* `params` (SubnetHyperparams):
+		/// 		- The new hyperparameters.
+		///
+		#[pallet::weight((0, DispatchClass::Operational, Pays::No))]
+		pub fn sudo_set_hyperparams(
+			origin: OriginFor<T>,
+			netuid: u16,
+			params: SubnetHyperparams
+		) -> DispatchResult {
+			Self::do_set_hyperparams( origin, netuid, params )
+		}
+
 		/// Set blocks per Step
 		/// #Args:
 		/// 	* 'origin': (<T as frame_system::Config>Origin):
@@ -827,96 +1723,318 @@ pub mod pallet {
 			blocks_per_step: u64
 		) -> DispatchResult {
 			ensure_root( origin )?;
+			ensure!( blocks_per_step >= T::MinimumBlocksPerStep::get() && blocks_per_step <= T::MaximumBlocksPerStep::get(), Error::<T>::BlocksPerStepOutOfRange );
 			BlocksPerStep::<T>::set( blocks_per_step );
 			Self::deposit_event( Event::BlocksPerStepSet( blocks_per_step ) );
 			Ok(())
 		}
 
+		/// Set the unbonding period
+		/// #Args:
+		/// 	* 'origin': (<T as frame_system::Config>Origin):
+		/// 		- The caller, must be sudo.
+		/// 		
+		#[pallet::weight((0, DispatchClass::Normal, Pays::No))]
+		pub fn sudo_set_unbonding_period(
+			origin: OriginFor<T>,
+			unbonding_period: u64
+		) -> DispatchResult {
+			ensure_root( origin )?;
+			ensure!( unbonding_period >= T::MinimumUnbondingPeriod::get() && unbonding_period <= T::MaximumUnbondingPeriod::get(), Error::<T>::UnbondingPeriodOutOfRange );
+			UnbondingPeriod::<T>::set( unbonding_period );
+			Self::deposit_event( Event::UnbondingPeriodSet( unbonding_period ) );
+			Ok(())
+		}
+
 		/// ---- Set emission ratio for each subnetwork
 		/// Args:
 		/// 	* 'origin': (<T as frame_system::Config>Origin):
 		/// 		- The caller, must be sudo.
 		/// 	* `netuid` (u16):
 		/// 		- The network uid we are setting emission ratio on.
+		/// 	* `subnet_emission_ratio` (u16):
+		/// 		- The share of block emission paid to this network as a proportion of u16::MAX.
 		/// 
 		#[pallet::weight((0, DispatchClass::Normal, Pays::No))]
 		pub fn sudo_set_emission_ratio (
-			_origin: OriginFor<T>,
-			_netuid: u16,
-			_subnet_emission_ratio: u16
+			origin: OriginFor<T>,
+			netuid: u16,
+			subnet_emission_ratio: u16
 		) -> DispatchResult{
-			ensure_root( _origin )?;
-				if Self::calculate_emission_ratio_sum() + _subnet_emission_ratio > 1 { 
-						 //we should return error /*To DO */
-				}
-				else{
-					EmissionRatio::<T>::insert(_netuid, _subnet_emission_ratio);
-				}
+			ensure_root( origin )?;
+			ensure!( Self::if_subnet_exist( netuid ), Error::<T>::NetworkDoesNotExist );
+			let other_ratios_sum: u64 = Self::calculate_emission_ratio_sum() - EmissionRatio::<T>::get( netuid ) as u64;
+			ensure!( other_ratios_sum + subnet_emission_ratio as u64 <= u16::MAX as u64, Error::<T>::InvalidEmissionRatio );
+			EmissionRatio::<T>::insert( netuid, subnet_emission_ratio );
+			Self::deposit_event( Event::EmissionRatioSet( netuid, subnet_emission_ratio ) );
 			Ok(())
 		}
+
+		/// ---- Sets the bonds moving average on a subnetwork, the share of the previous
+		/// bonds kept at each epoch in parts per million.
+		/// # Args:
+		/// 	* 'origin': (<T as frame_system::Config>Origin):
+		/// 		- The caller, must be sudo.
+		/// 	* `netuid` (u16):
+		/// 		- The network to set the value on.
+		/// 	* `bonds_moving_average` (u64):
+		/// 		- The new value.
+		///
 		#[pallet::weight((0, DispatchClass::Operational, Pays::No))]
 		pub fn sudo_set_bonds_moving_average ( 
-			_origin:OriginFor<T>, 
-			_bonds_moving_average: u64 
+			origin:OriginFor<T>, 
+			netuid: u16,
+			bonds_moving_average: u64 
 		) -> DispatchResult {  
-			ensure_root( _origin )?; /*TO DO */
+			ensure_root( origin )?;
+			ensure!( Self::if_subnet_exist( netuid ), Error::<T>::NetworkDoesNotExist );
+			ensure!( bonds_moving_average >= T::MinimumBondsMovingAverage::get() && bonds_moving_average <= T::MaximumBondsMovingAverage::get(), Error::<T>::BondsMovingAverageOutOfRange );
+			BondsMovingAverage::<T>::insert( netuid, bonds_moving_average );
+			Self::deposit_event( Event::BondsMovingAverageSet( netuid, bonds_moving_average ) );
 			Ok(())
 		}
 
+		/// ---- Sets the trust threshold on a subnetwork, the smallest weight as a proportion
+		/// of u16::MAX which counts towards trust.
+		/// # Args:
+		/// 	* 'origin': (<T as frame_system::Config>Origin):
+		/// 		- The caller, must be sudo.
+		/// 	* `netuid` (u16):
+		/// 		- The network to set the value on.
+		/// 	* `trust_threshold` (u16):
+		/// 		- The new value.
+		///
+		#[pallet::weight((0, DispatchClass::Operational, Pays::No))]
+		pub fn sudo_set_trust_threshold ( 
+			origin:OriginFor<T>, 
+			netuid: u16,
+			trust_threshold: u16 
+		) -> DispatchResult {  
+			ensure_root( origin )?;
+			ensure!( Self::if_subnet_exist( netuid ), Error::<T>::NetworkDoesNotExist );
+			ensure!( trust_threshold >= T::MinimumTrustThreshold::get() && trust_threshold <= T::MaximumTrustThreshold::get(), Error::<T>::TrustThresholdOutOfRange );
+			TrustThreshold::<T>::insert( netuid, trust_threshold );
+			Self::deposit_event( Event::TrustThresholdSet( netuid, trust_threshold ) );
+			Ok(())
+		}
+
+		/// ---- Sets the number of neurons, taken in order of stake, which hold a validator
+		/// permit on a subnetwork. Only the weights of permitted neurons count towards consensus.
+		/// # Args:
+		/// 	* 'origin': (<T as frame_system::Config>Origin):
+		/// 		- The caller, must be sudo.
+		/// 	* `netuid` (u16):
+		/// 		- The network to set the value on.
+		/// 	* `max_allowed_validators` (u16):
+		/// 		- The new value.
+		///
+		#[pallet::weight((0, DispatchClass::Operational, Pays::No))]
+		pub fn sudo_set_max_allowed_validators ( 
+			origin:OriginFor<T>, 
+			netuid: u16,
+			max_allowed_validators: u16 
+		) -> DispatchResult {  
+			ensure_root( origin )?;
+			ensure!( Self::if_subnet_exist( netuid ), Error::<T>::NetworkDoesNotExist );
+			ensure!( max_allowed_validators >= T::MinimumMaxAllowedValidators::get() && max_allowed_validators <= T::MaximumMaxAllowedValidators::get(), Error::<T>::MaxAllowedValidatorsOutOfRange );
+			MaxAllowedValidators::<T>::insert( netuid, max_allowed_validators );
+			Self::deposit_event( Event::MaxAllowedValidatorsSet( netuid, max_allowed_validators ) );
+			Ok(())
+		}
+
+		/// ---- Sets the registration difficulty on a subnetwork.
+		/// # Args:
+		/// 	* 'origin': (<T as frame_system::Config>Origin):
+		/// 		- The caller, must be sudo.
+		/// 	* `netuid` (u16):
+		/// 		- The network to set the value on.
+		/// 	* `difficulty` (u64):
+		/// 		- The new value.
+		///
 		#[pallet::weight((0, DispatchClass::Operational, Pays::No))]
 		pub fn sudo_set_difficulty ( 
-			_origin:OriginFor<T>, 
-			_difficulty: u64 
+			origin:OriginFor<T>, 
+			netuid: u16,
+			difficulty: u64 
 		) -> DispatchResult {
-			ensure_root( _origin )?;
-			Ok(())  /*TO DO */
+			ensure_root( origin )?;
+			ensure!( Self::if_subnet_exist( netuid ), Error::<T>::NetworkDoesNotExist );
+			ensure!( difficulty >= T::MinimumDifficulty::get() && difficulty <= T::MaximumDifficulty::get(), Error::<T>::DifficultyOutOfRange );
+			Difficulty::<T>::insert( netuid, difficulty );
+			Self::deposit_event( Event::DifficultySet( netuid, difficulty ) );
+			Ok(())
 		}
 
+		/// ---- Sets the number of blocks between difficulty adjustments on a subnetwork.
+		/// # Args:
+		/// 	* 'origin': (<T as frame_system::Config>Origin):
+		/// 		- The caller, must be sudo.
+		/// 	* `netuid` (u16):
+		/// 		- The network to set the value on.
+		/// 	* `adjustment_interval` (u16):
+		/// 		- The new value.
+		///
 		#[pallet::weight((0, DispatchClass::Operational, Pays::No))]
 		pub fn sudo_set_adjustment_interval ( 
-			_origin:OriginFor<T>, 
-			_adjustment_interval: u64 
+			origin:OriginFor<T>, 
+			netuid: u16,
+			adjustment_interval: u16 
 		) -> DispatchResult {
-			ensure_root( _origin )?;
-			Ok(())  /*TO DO */
+			ensure_root( origin )?;
+			ensure!( Self::if_subnet_exist( netuid ), Error::<T>::NetworkDoesNotExist );
+			ensure!( adjustment_interval >= T::MinimumAdjustmentInterval::get() && adjustment_interval <= T::MaximumAdjustmentInterval::get(), Error::<T>::AdjustmentIntervalOutOfRange );
+			AdjustmentInterval::<T>::insert( netuid, adjustment_interval );
+			Self::deposit_event( Event::AdjustmentIntervalSet( netuid, adjustment_interval ) );
+			Ok(())
 		}
 
+		/// ---- Sets the target number of registrations per adjustment interval on a subnetwork.
+		/// # Args:
+		/// 	* 'origin': (<T as frame_system::Config>Origin):
+		/// 		- The caller, must be sudo.
+		/// 	* `netuid` (u16):
+		/// 		- The network to set the value on.
+		/// 	* `target_registrations_per_interval` (u16):
+		/// 		- The new value.
+		///
 		#[pallet::weight((0, DispatchClass::Operational, Pays::No))]
-		pub fn sudo_target_registrations_per_interval ( 
-			_origin:OriginFor<T>, 
-			_target_registrations_per_interval: u64 
+		pub fn sudo_set_target_registrations_per_interval ( 
+			origin:OriginFor<T>, 
+			netuid: u16,
+			target_registrations_per_interval: u16 
 		) -> DispatchResult {
-			ensure_root( _origin )?; /* TO DO */
+			ensure_root( origin )?;
+			ensure!( Self::if_subnet_exist( netuid ), Error::<T>::NetworkDoesNotExist );
+			ensure!( target_registrations_per_interval >= T::MinimumTargetRegistrationsPerInterval::get() && target_registrations_per_interval <= T::MaximumTargetRegistrationsPerInterval::get(), Error::<T>::TargetRegistrationsPerIntervalOutOfRange );
+			TargetRegistrationsPerInterval::<T>::insert( netuid, target_registrations_per_interval );
+			Self::deposit_event( Event::TargetRegistrationsPerIntervalSet( netuid, target_registrations_per_interval ) );
 			Ok(())
 		}
-		
+
+		/// ---- Sets the maximum number of registrations per block on a subnetwork.
+		/// # Args:
+		/// 	* 'origin': (<T as frame_system::Config>Origin):
+		/// 		- The caller, must be sudo.
+		/// 	* `netuid` (u16):
+		/// 		- The network to set the value on.
+		/// 	* `max_registrations_per_block` (u16):
+		/// 		- The new value.
+		///
+		#[pallet::weight((0, DispatchClass::Operational, Pays::No))]
+		pub fn sudo_set_max_registrations_per_block ( 
+			origin:OriginFor<T>, 
+			netuid: u16,
+			max_registrations_per_block: u16 
+		) -> DispatchResult {
+			ensure_root( origin )?;
+			ensure!( Self::if_subnet_exist( netuid ), Error::<T>::NetworkDoesNotExist );
+			ensure!( max_registrations_per_block >= T::MinimumMaxRegistrationsPerBlock::get() && max_registrations_per_block <= T::MaximumMaxRegistrationsPerBlock::get(), Error::<T>::MaxRegistrationsPerBlockOutOfRange );
+			MaxRegistrationsPerBlock::<T>::insert( netuid, max_registrations_per_block );
+			Self::deposit_event( Event::MaxRegistrationsPerBlockSet( netuid, max_registrations_per_block ) );
+			Ok(())
+		}
+
+		/// ---- Sets the number of steps between epochs on a subnetwork.
+		/// # Args:
+		/// 	* 'origin': (<T as frame_system::Config>Origin):
+		/// 		- The caller, must be sudo.
+		/// 	* `netuid` (u16):
+		/// 		- The network to set the value on.
+		/// 	* `tempo` (u16):
+		/// 		- The new value.
+		///
+		#[pallet::weight((0, DispatchClass::Operational, Pays::No))]
+		pub fn sudo_set_tempo ( 
+			origin:OriginFor<T>, 
+			netuid: u16,
+			tempo: u16 
+		) -> DispatchResult {
+			ensure_root( origin )?;
+			ensure!( Self::if_subnet_exist( netuid ), Error::<T>::NetworkDoesNotExist );
+			ensure!( tempo >= T::MinimumTempo::get() && tempo <= T::MaximumTempo::get(), Error::<T>::TempoOutOfRange );
+			Tempo::<T>::insert( netuid, tempo );
+			Self::deposit_event( Event::TempoSet( netuid, tempo ) );
+			Ok(())
+		}
+
+		/// ---- Sets the number of blocks after which a neuron without weights is inactive on a subnetwork.
+		/// # Args:
+		/// 	* 'origin': (<T as frame_system::Config>Origin):
+		/// 		- The caller, must be sudo.
+		/// 	* `netuid` (u16):
+		/// 		- The network to set the value on.
+		/// 	* `activity_cutoff` (u16):
+		/// 		- The new value.
+		///
 		#[pallet::weight((0, DispatchClass::Operational, Pays::No))]
 		pub fn sudo_set_activity_cutoff ( 
-			_origin:OriginFor<T>, 
-			_activity_cutoff: u16 
+			origin:OriginFor<T>, 
+			netuid: u16,
+			activity_cutoff: u16 
 		) -> DispatchResult {
-			ensure_root( _origin )?;/*TO DO */
+			ensure_root( origin )?;
+			ensure!( Self::if_subnet_exist( netuid ), Error::<T>::NetworkDoesNotExist );
+			ensure!( activity_cutoff >= T::MinimumActivityCutoff::get() && activity_cutoff <= T::MaximumActivityCutoff::get(), Error::<T>::ActivityCutoffOutOfRange );
+			ActivityCutoff::<T>::insert( netuid, activity_cutoff );
+			Self::deposit_event( Event::ActivityCutoffSet( netuid, activity_cutoff ) );
 			Ok(())
 		}
 
+		/// ---- Sets the steepness of the consensus sigmoid on a subnetwork.
+		/// # Args:
+		/// 	* 'origin': (<T as frame_system::Config>Origin):
+		/// 		- The caller, must be sudo.
+		/// 	* `netuid` (u16):
+		/// 		- The network to set the value on.
+		/// 	* `rho` (u16):
+		/// 		- The new value.
+		///
 		#[pallet::weight((0, DispatchClass::Operational, Pays::No))]
 		pub fn sudo_set_rho ( 
-			_origin:OriginFor<T>, 
-			_rho: u64 
+			origin:OriginFor<T>, 
+			netuid: u16,
+			rho: u16 
 		) -> DispatchResult {
-			ensure_root( _origin )?; /*TO DO */
+			ensure_root( origin )?;
+			ensure!( Self::if_subnet_exist( netuid ), Error::<T>::NetworkDoesNotExist );
+			ensure!( rho >= T::MinimumRho::get() && rho <= T::MaximumRho::get(), Error::<T>::RhoOutOfRange );
+			Rho::<T>::insert( netuid, rho );
+			Self::deposit_event( Event::RhoSet( netuid, rho ) );
 			Ok(())
 		}
 
+		/// ---- Sets the consensus threshold as a proportion of u16::MAX on a subnetwork.
+		/// # Args:
+		/// 	* 'origin': (<T as frame_system::Config>Origin):
+		/// 		- The caller, must be sudo.
+		/// 	* `netuid` (u16):
+		/// 		- The network to set the value on.
+		/// 	* `kappa` (u16):
+		/// 		- The new value.
+		///
 		#[pallet::weight((0, DispatchClass::Operational, Pays::No))]
 		pub fn sudo_set_kappa ( 
-			_origin:OriginFor<T>, 
-			_kappa: u16 
+			origin:OriginFor<T>, 
+			netuid: u16,
+			kappa: u16 
 		) -> DispatchResult {
-			ensure_root( _origin )?; /*TO DO */
+			ensure_root( origin )?;
+			ensure!( Self::if_subnet_exist( netuid ), Error::<T>::NetworkDoesNotExist );
+			ensure!( kappa >= T::MinimumKappa::get() && kappa <= T::MaximumKappa::get(), Error::<T>::KappaOutOfRange );
+			Kappa::<T>::insert( netuid, kappa );
+			Self::deposit_event( Event::KappaSet( netuid, kappa ) );
 			Ok(())
 		}
 
+		/// ---- Sets the maximum number of uids on a subnetwork.
+		/// # Args:
+		/// 	* 'origin': (<T as frame_system::Config>Origin):
+		/// 		- The caller, must be sudo.
+		/// 	* `netuid` (u16):
+		/// 		- The network to set the value on.
+		/// 	* `max_allowed_uids` (u16):
+		/// 		- The new maximum number of uids.
+		///
 		#[pallet::weight((0, DispatchClass::Operational, Pays::No))]
 		pub fn sudo_set_max_allowed_uids ( 
 			origin:OriginFor<T>,
@@ -924,90 +2042,273 @@ pub mod pallet {
 			max_allowed_uids: u16 
 		) -> DispatchResult {
 			ensure_root( origin )?;
-			ensure!( max_allowed_uids < u16::MAX, Error::<T>::MaxAllowedUIdsNotAllowed );
+			ensure!( Self::if_subnet_exist( netuid ), Error::<T>::NetworkDoesNotExist );
+			ensure!( max_allowed_uids >= T::MinimumMaxAllowedUids::get() && max_allowed_uids <= T::MaximumMaxAllowedUids::get(), Error::<T>::MaxAllowedUidsOutOfRange );
+			ensure!( max_allowed_uids >= SubnetworkN::<T>::get( netuid ), Error::<T>::MaxAllowedUidsLessThanCurrentUids );
 			MaxAllowedUids::<T>::insert(netuid, max_allowed_uids);
 			Self::deposit_event( Event::MaxAllowedUidsSet( netuid, max_allowed_uids) );
 			Ok(())
 		}
 
+		/// ---- Sets the minimum number of weights a neuron must set on a subnetwork.
+		/// # Args:
+		/// 	* 'origin': (<T as frame_system::Config>Origin):
+		/// 		- The caller, must be sudo.
+		/// 	* `netuid` (u16):
+		/// 		- The network to set the value on.
+		/// 	* `min_allowed_weights` (u16):
+		/// 		- The new value.
+		///
 		#[pallet::weight((0, DispatchClass::Operational, Pays::No))]
 		pub fn sudo_set_min_allowed_weights ( 
-			_origin:OriginFor<T>, 
-			_min_allowed_weights: u64 
+			origin:OriginFor<T>, 
+			netuid: u16,
+			min_allowed_weights: u16 
 		) -> DispatchResult {
-			ensure_root( _origin )?; /* TO DO */
+			ensure_root( origin )?;
+			ensure!( Self::if_subnet_exist( netuid ), Error::<T>::NetworkDoesNotExist );
+			ensure!( min_allowed_weights >= T::MinimumMinAllowedWeights::get() && min_allowed_weights <= T::MaximumMinAllowedWeights::get(), Error::<T>::MinAllowedWeightsOutOfRange );
+			MinAllowedWeights::<T>::insert( netuid, min_allowed_weights );
+			Self::deposit_event( Event::MinAllowedWeightSet( netuid, min_allowed_weights ) );
 			Ok(())
 		}
 
+		/// ---- Sets the maximum ratio between the largest and smallest weight on a subnetwork.
+		/// # Args:
+		/// 	* 'origin': (<T as frame_system::Config>Origin):
+		/// 		- The caller, must be sudo.
+		/// 	* `netuid` (u16):
+		/// 		- The network to set the value on.
+		/// 	* `max_allowed_max_min_ratio` (u16):
+		/// 		- The new value.
+		///
 		#[pallet::weight((0, DispatchClass::Operational, Pays::No))]
 		pub fn sudo_set_max_allowed_max_min_ratio ( 
-			_origin:OriginFor<T>, 
-			_max_allowed_max_min_ratio: u16 
+			origin:OriginFor<T>, 
+			netuid: u16,
+			max_allowed_max_min_ratio: u16 
 		) -> DispatchResult {
-			ensure_root( _origin )?; /*TO DO */
+			ensure_root( origin )?;
+			ensure!( Self::if_subnet_exist( netuid ), Error::<T>::NetworkDoesNotExist );
+			ensure!( max_allowed_max_min_ratio >= T::MinimumMaxAllowedMaxMinRatio::get() && max_allowed_max_min_ratio <= T::MaximumMaxAllowedMaxMinRatio::get(), Error::<T>::MaxAllowedMaxMinRatioOutOfRange );
+			MaxAllowedMaxMinRatio::<T>::insert( netuid, max_allowed_max_min_ratio );
+			Self::deposit_event( Event::MaxAllowedMaxMinRatioSet( netuid, max_allowed_max_min_ratio ) );
 			Ok(())
 		}
 
+		/// ---- Sets the validator batch size on a subnetwork.
+		/// # Args:
+		/// 	* 'origin': (<T as frame_system::Config>Origin):
+		/// 		- The caller, must be sudo.
+		/// 	* `netuid` (u16):
+		/// 		- The network to set the value on.
+		/// 	* `validator_batch_size` (u16):
+		/// 		- The new value.
+		///
 		#[pallet::weight((0, DispatchClass::Operational, Pays::No))]
 		pub fn sudo_set_validator_batch_size ( 
-			_origin:OriginFor<T>, 
-			_validator_batch_size: u16 
+			origin:OriginFor<T>, 
+			netuid: u16,
+			validator_batch_size: u16 
 		) -> DispatchResult {
-			ensure_root( _origin )?; /*TO DO */
+			ensure_root( origin )?;
+			ensure!( Self::if_subnet_exist( netuid ), Error::<T>::NetworkDoesNotExist );
+			ensure!( validator_batch_size >= T::MinimumValidatorBatchSize::get() && validator_batch_size <= T::MaximumValidatorBatchSize::get(), Error::<T>::ValidatorBatchSizeOutOfRange );
+			ValidatorBatchSize::<T>::insert( netuid, validator_batch_size );
+			Self::deposit_event( Event::ValidatorBatchSizeSet( netuid, validator_batch_size ) );
 			Ok(())
 		}
 
+		/// ---- Sets the validator sequence length on a subnetwork.
+		/// # Args:
+		/// 	* 'origin': (<T as frame_system::Config>Origin):
+		/// 		- The caller, must be sudo.
+		/// 	* `netuid` (u16):
+		/// 		- The network to set the value on.
+		/// 	* `validator_sequence_length` (u16):
+		/// 		- The new value.
+		///
 		#[pallet::weight((0, DispatchClass::Operational, Pays::No))]
 		pub fn sudo_set_validator_sequence_length ( 
-			_origin:OriginFor<T>, 
-			_validator_sequence_length: u16 
+			origin:OriginFor<T>, 
+			netuid: u16,
+			validator_sequence_length: u16 
 		) -> DispatchResult {
-			ensure_root( _origin )?;  /*TO DO */
+			ensure_root( origin )?;
+			ensure!( Self::if_subnet_exist( netuid ), Error::<T>::NetworkDoesNotExist );
+			ensure!( validator_sequence_length >= T::MinimumValidatorSequenceLength::get() && validator_sequence_length <= T::MaximumValidatorSequenceLength::get(), Error::<T>::ValidatorSequenceLengthOutOfRange );
+			ValidatorSequenceLength::<T>::insert( netuid, validator_sequence_length );
+			Self::deposit_event( Event::ValidatorSequenceLengthSet( netuid, validator_sequence_length ) );
 			Ok(())
 		}
 
+		/// ---- Sets the validator epoch length on a subnetwork.
+		/// # Args:
+		/// 	* 'origin': (<T as frame_system::Config>Origin):
+		/// 		- The caller, must be sudo.
+		/// 	* `netuid` (u16):
+		/// 		- The network to set the value on.
+		/// 	* `validator_epoch_length` (u16):
+		/// 		- The new value.
+		///
+		#[pallet::weight((0, DispatchClass::Operational, Pays::No))]
+		pub fn sudo_set_validator_epoch_length ( 
+			origin:OriginFor<T>, 
+			netuid: u16,
+			validator_epoch_length: u16 
+		) -> DispatchResult {
+			ensure_root( origin )?;
+			ensure!( Self::if_subnet_exist( netuid ), Error::<T>::NetworkDoesNotExist );
+			ensure!( validator_epoch_length >= T::MinimumValidatorEpochLength::get() && validator_epoch_length <= T::MaximumValidatorEpochLength::get(), Error::<T>::ValidatorEpochLengthOutOfRange );
+			ValidatorEpochLen::<T>::insert( netuid, validator_epoch_length );
+			Self::deposit_event( Event::ValidatorEpochLengthSet( netuid, validator_epoch_length ) );
+			Ok(())
+		}
+
+		/// ---- Sets the number of validator epochs between resets on a subnetwork.
+		/// # Args:
+		/// 	* 'origin': (<T as frame_system::Config>Origin):
+		/// 		- The caller, must be sudo.
+		/// 	* `netuid` (u16):
+		/// 		- The network to set the value on.
+		/// 	* `validator_epochs_per_reset` (u16):
+		/// 		- The new value.
+		///
 		#[pallet::weight((0, DispatchClass::Operational, Pays::No))]
 		pub fn sudo_set_validator_epochs_per_reset ( 
-			_origin:OriginFor<T>, 
-			_validator_epochs_per_reset : u16 
+			origin:OriginFor<T>, 
+			netuid: u16,
+			validator_epochs_per_reset: u16 
 		) -> DispatchResult {
-			ensure_root( _origin )?; /*TO DO */
+			ensure_root( origin )?;
+			ensure!( Self::if_subnet_exist( netuid ), Error::<T>::NetworkDoesNotExist );
+			ensure!( validator_epochs_per_reset >= T::MinimumValidatorEpochsPerReset::get() && validator_epochs_per_reset <= T::MaximumValidatorEpochsPerReset::get(), Error::<T>::ValidatorEpochsPerResetOutOfRange );
+			ValidatorEpochsPerReset::<T>::insert( netuid, validator_epochs_per_reset );
+			Self::deposit_event( Event::ValidatorEpochsPerResetSet( netuid, validator_epochs_per_reset ) );
 			Ok(())
 		}
 
+		/// ---- Sets the incentive pruning denominator on a subnetwork.
+		/// # Args:
+		/// 	* 'origin': (<T as frame_system::Config>Origin):
+		/// 		- The caller, must be sudo.
+		/// 	* `netuid` (u16):
+		/// 		- The network to set the value on.
+		/// 	* `incentive_pruning_denominator` (u16):
+		/// 		- The new value.
+		///
 		#[pallet::weight((0, DispatchClass::Operational, Pays::No))]
-		pub fn sudo_set_incentive_pruning_denominator( 
-			_origin:OriginFor<T>, 
-			_incentive_pruning_denominator: u16 
+		pub fn sudo_set_incentive_pruning_denominator ( 
+			origin:OriginFor<T>, 
+			netuid: u16,
+			incentive_pruning_denominator: u16 
 		) -> DispatchResult {
-			ensure_root( _origin )?; /*TO DO */
+			ensure_root( origin )?;
+			ensure!( Self::if_subnet_exist( netuid ), Error::<T>::NetworkDoesNotExist );
+			ensure!( incentive_pruning_denominator >= T::MinimumIncentivePruningDenominator::get() && incentive_pruning_denominator <= T::MaximumIncentivePruningDenominator::get(), Error::<T>::IncentivePruningDenominatorOutOfRange );
+			IncentivePruningDenominator::<T>::insert( netuid, incentive_pruning_denominator );
+			Self::deposit_event( Event::IncentivePruningDenominatorSet( netuid, incentive_pruning_denominator ) );
 			Ok(())
 		}
 
+		/// ---- Sets the stake pruning denominator on a subnetwork.
+		/// # Args:
+		/// 	* 'origin': (<T as frame_system::Config>Origin):
+		/// 		- The caller, must be sudo.
+		/// 	* `netuid` (u16):
+		/// 		- The network to set the value on.
+		/// 	* `stake_pruning_denominator` (u16):
+		/// 		- The new value.
+		///
 		#[pallet::weight((0, DispatchClass::Operational, Pays::No))]
-		pub fn sudo_set_stake_pruning_denominator( 
-			_origin:OriginFor<T>, 
-			_stake_pruning_denominator: u16 
+		pub fn sudo_set_stake_pruning_denominator ( 
+			origin:OriginFor<T>, 
+			netuid: u16,
+			stake_pruning_denominator: u16 
 		) -> DispatchResult {
-			ensure_root( _origin )?; /*TO DO */
+			ensure_root( origin )?;
+			ensure!( Self::if_subnet_exist( netuid ), Error::<T>::NetworkDoesNotExist );
+			ensure!( stake_pruning_denominator >= T::MinimumStakePruningDenominator::get() && stake_pruning_denominator <= T::MaximumStakePruningDenominator::get(), Error::<T>::StakePruningDenominatorOutOfRange );
+			StakePruningDenominator::<T>::insert( netuid, stake_pruning_denominator );
+			Self::deposit_event( Event::StakePruningDenominatorSet( netuid, stake_pruning_denominator ) );
 			Ok(())
 		}
 
+		/// ---- Sets the stake below which a neuron scores zero stake when pruning on a subnetwork.
+		/// # Args:
+		/// 	* 'origin': (<T as frame_system::Config>Origin):
+		/// 		- The caller, must be sudo.
+		/// 	* `netuid` (u16):
+		/// 		- The network to set the value on.
+		/// 	* `stake_pruning_min` (u16):
+		/// 		- The new value.
+		///
+		#[pallet::weight((0, DispatchClass::Operational, Pays::No))]
+		pub fn sudo_set_stake_pruning_min ( 
+			origin:OriginFor<T>, 
+			netuid: u16,
+			stake_pruning_min: u16 
+		) -> DispatchResult {
+			ensure_root( origin )?;
+			ensure!( Self::if_subnet_exist( netuid ), Error::<T>::NetworkDoesNotExist );
+			ensure!( stake_pruning_min >= T::MinimumStakePruningMin::get() && stake_pruning_min <= T::MaximumStakePruningMin::get(), Error::<T>::StakePruningMinOutOfRange );
+			StakePruningMin::<T>::insert( netuid, stake_pruning_min );
+			Self::deposit_event( Event::StakePruningMinSet( netuid, stake_pruning_min ) );
+			Ok(())
+		}
+
+		/// ---- Sets the number of blocks after registration during which a neuron cannot be pruned on a subnetwork.
+		/// # Args:
+		/// 	* 'origin': (<T as frame_system::Config>Origin):
+		/// 		- The caller, must be sudo.
+		/// 	* `netuid` (u16):
+		/// 		- The network to set the value on.
+		/// 	* `immunity_period` (u16):
+		/// 		- The new value.
+		///
 		#[pallet::weight((0, DispatchClass::Operational, Pays::No))]
 		pub fn sudo_set_immunity_period ( 
-			_origin:OriginFor<T>, 
-			_immunity_period: u16 
+			origin:OriginFor<T>, 
+			netuid: u16,
+			immunity_period: u16 
+		) -> DispatchResult {
+			ensure_root( origin )?;
+			ensure!( Self::if_subnet_exist( netuid ), Error::<T>::NetworkDoesNotExist );
+			ensure!( immunity_period >= T::MinimumImmunityPeriod::get() && immunity_period <= T::MaximumImmunityPeriod::get(), Error::<T>::ImmunityPeriodOutOfRange );
+			ImmunityPeriod::<T>::insert( netuid, immunity_period );
+			Self::deposit_event( Event::ImmunityPeriodSet( netuid, immunity_period ) );
+			Ok(())
+		}
+
+		/// ---- Clears every bond held on a subnetwork. For emergencies, when the bonds no
+		/// longer reflect the neurons registered on the network.
+		/// # Args:
+		/// 	* 'origin': (<T as frame_system::Config>Origin):
+		/// 		- The caller, must be sudo.
+		/// 	* `netuid` (u16):
+		/// 		- The network to reset the bonds on.
+		///
+		#[pallet::weight((0, DispatchClass::Operational, Pays::No))]
+		pub fn reset_bonds ( 
+			origin:OriginFor<T>, 
+			netuid: u16
 		) -> DispatchResult {
-			ensure_root( _origin )?; /*TO DO */
+			ensure_root( origin )?;
+			ensure!( Self::if_subnet_exist( netuid ), Error::<T>::NetworkDoesNotExist );
+			Bonds::<T>::remove_prefix( netuid, None );
+			NeuronsToPruneAtNextEpoch::<T>::remove( netuid );
+			Self::deposit_event( Event::BondsReset( netuid ) );
 			Ok(())
 		}
-		/*TO DO: reset_bonds function  */ 
 	}
 	/// ---- Paratensor helper functions.
 	impl<T: Config> Pallet<T> {
 	/// ---- returns the sum of emission ratios for defined subnetworks
-		pub fn calculate_emission_ratio_sum() -> u16 {
-			let sum : u16 = 0; /*TO DO */
+		pub fn calculate_emission_ratio_sum() -> u64 {
+			let mut sum : u64 = 0;
+			for ( _, ratio ) in EmissionRatio::<T>::iter() {
+				sum += ratio as u64;
+			}
 			sum
 		}
 	}	
@@ -0,0 +1,453 @@
+use super::*;
+use frame_support::inherent::Vec;
+use frame_support::sp_std::vec;
+use frame_support::weights::Weight;
+use frame_support::traits::{Get, GetStorageVersion, PalletInfoAccess, StorageVersion};
+use frame_support::storage::{IterableStorageDoubleMap, IterableStorageMap};
+use frame_support::storage::migration::{get_storage_value, put_storage_value, remove_storage_prefix, storage_key_iter, take_storage_value};
+use frame_support::{Blake2_128Concat, Identity};
+use codec::Encode;
+
+/// Storage item name of the block at registration map.
+const BLOCK_AT_REGISTRATION: &[u8] = b"BlockAtRegistration";
+
+/// Storage item name of the neurons to prune map.
+const NEURONS_TO_PRUNE_AT_NEXT_EPOCH: &[u8] = b"NeuronsToPruneAtNextEpoch";
+
+/// Storage item name of the retired hotkey to coldkey map.
+const HOTKEYS: &[u8] = b"Hotkeys";
+
+/// Storage item name of the retired coldkey to hotkey map.
+const COLDKEYS: &[u8] = b"Coldkeys";
+
+/// Storage item name of the max registrations per block value, now a per network map.
+const MAX_REGISTRATIONS_PER_BLOCK: &[u8] = b"MaxRegistrationsPerBlock";
+
+/// Storage item name of the registrations this block counter, now a per network map.
+const REGISTRATIONS_THIS_BLOCK: &[u8] = b"RegistrationsThisBlock";
+
+/// Storage item name of the adjustment interval value, now a per network map.
+const ADJUSTMENT_INTERVAL: &[u8] = b"AdjustmentInterval";
+
+/// Storage item name of the target registrations per interval value, now a per network map.
+const TARGET_REGISTRATIONS_PER_INTERVAL: &[u8] = b"TargetRegistrationsPerInterval";
+
+/// Moves the entry under (netuid, old_uid) to (netuid, new_uid) if one exists.
+macro_rules! move_uid_entry {
+    ( $map:ident, $netuid:expr, $old_uid:expr, $new_uid:expr ) => {
+        if $map::<T>::contains_key( $netuid, $old_uid ) {
+            let value = $map::<T>::take( $netuid, $old_uid );
+            $map::<T>::insert( $netuid, $new_uid, value );
+        }
+    };
+}
+
+impl<T: Config> Pallet<T> {
+
+    /// ---- Remaps uids from the global GlobalN counter onto dense per network uids,
+    /// so that the uids of each network are exactly 0..SubnetworkN(netuid).
+    /// Every storage item keyed by uid and the uid columns of the weights and bonds
+    /// rows are moved onto the new uids. Runs once, when the on chain storage version is 0.
+    ///
+    /// # Returns:
+    /// 	* 'Weight':
+    /// 		- The weight consumed by the migration.
+    ///
+    pub fn migrate_to_v1_dense_uids() -> Weight {
+        let mut weight: Weight = T::DbWeight::get().reads( 1 );
+        if Pallet::<T>::on_chain_storage_version() != 0 { return weight }
+
+        // --- 1. Collect the registered uids of every network, sorted ascending.
+        let mut networks: Vec<(u16, Vec<u16>)> = vec![];
+        for ( netuid, uid, _ ) in <Keys<T> as IterableStorageDoubleMap<u16, u16, T::AccountId>>::iter() {
+            weight = weight.saturating_add( T::DbWeight::get().reads( 1 ) );
+            match networks.iter_mut().find( |( network, _ )| *network == netuid ) {
+                Some( ( _, uids ) ) => uids.push( uid ),
+                None => networks.push( ( netuid, vec![ uid ] ) ),
+            }
+        }
+
+        for ( netuid, mut old_uids ) in networks {
+            old_uids.sort();
+
+            // --- 2. Move each uid down onto its dense position. Processing in ascending order
+            // means the target position has always been vacated already.
+            for ( new_uid, old_uid ) in old_uids.iter().enumerate() {
+                let new_uid: u16 = new_uid as u16;
+                let old_uid: u16 = *old_uid;
+                if new_uid == old_uid { continue }
+                let hotkey: T::AccountId = Keys::<T>::take( netuid, old_uid );
+                Keys::<T>::insert( netuid, new_uid, hotkey.clone() );
+                Uids::<T>::insert( netuid, hotkey, new_uid );
+                move_uid_entry!( Weights, netuid, old_uid, new_uid );
+                move_uid_entry!( Bonds, netuid, old_uid, new_uid );
+                move_uid_entry!( S, netuid, old_uid, new_uid );
+                move_uid_entry!( Rank, netuid, old_uid, new_uid );
+                move_uid_entry!( Trust, netuid, old_uid, new_uid );
+                move_uid_entry!( Incentive, netuid, old_uid, new_uid );
+                move_uid_entry!( Consensus, netuid, old_uid, new_uid );
+                move_uid_entry!( Dividends, netuid, old_uid, new_uid );
+                move_uid_entry!( Emission, netuid, old_uid, new_uid );
+                move_uid_entry!( PrunningScores, netuid, old_uid, new_uid );
+                // BlockAtRegistration is keyed by uid alone at this version. Old global uids were unique,
+                // so keep the latest block when two networks now share a uid.
+                let pallet_name: &[u8] = <Pallet<T> as PalletInfoAccess>::name().as_bytes();
+                let old_block: u64 = get_storage_value::<u64>( pallet_name, BLOCK_AT_REGISTRATION, &old_uid.encode() ).unwrap_or( 0 );
+                let new_block: u64 = get_storage_value::<u64>( pallet_name, BLOCK_AT_REGISTRATION, &new_uid.encode() ).unwrap_or( 0 );
+                put_storage_value( pallet_name, BLOCK_AT_REGISTRATION, &new_uid.encode(), old_block.max( new_block ) );
+                // NeuronsToPruneAtNextEpoch holds a single uid at this version.
+                if get_storage_value::<u16>( pallet_name, NEURONS_TO_PRUNE_AT_NEXT_EPOCH, &netuid.encode() ) == Some( old_uid ) {
+                    put_storage_value( pallet_name, NEURONS_TO_PRUNE_AT_NEXT_EPOCH, &netuid.encode(), new_uid );
+                }
+                weight = weight.saturating_add( T::DbWeight::get().reads_writes( 14, 14 ) );
+            }
+
+            // --- 3. Rewrite the uid columns of the weights and bonds rows.
+            let remap = |row: Vec<(u16, u16)>| -> Vec<(u16, u16)> {
+                row.into_iter()
+                    .filter_map( |( uid_j, value )| old_uids.iter().position( |old| *old == uid_j ).map( |new_j| ( new_j as u16, value ) ) )
+                    .collect()
+            };
+            for new_uid in 0..old_uids.len() as u16 {
+                if Weights::<T>::contains_key( netuid, new_uid ) { Weights::<T>::insert( netuid, new_uid, remap( Weights::<T>::get( netuid, new_uid ) ) ); }
+                if Bonds::<T>::contains_key( netuid, new_uid ) { Bonds::<T>::insert( netuid, new_uid, remap( Bonds::<T>::get( netuid, new_uid ) ) ); }
+                weight = weight.saturating_add( T::DbWeight::get().reads_writes( 2, 2 ) );
+            }
+
+            // --- 4. The network size is now the number of registered uids.
+            SubnetworkN::<T>::insert( netuid, old_uids.len() as u16 );
+            weight = weight.saturating_add( T::DbWeight::get().writes( 1 ) );
+        }
+
+        StorageVersion::new( 1 ).put::<Pallet<T>>();
+        weight.saturating_add( T::DbWeight::get().writes( 1 ) )
+    }
+
+    /// ---- Rekeys BlockAtRegistration from uid to ( netuid, uid ). The block stored under
+    /// a uid is copied to every network on which that uid is registered. Runs once, when
+    /// the on chain storage version is 1.
+    ///
+    /// # Returns:
+    /// 	* 'Weight':
+    /// 		- The weight consumed by the migration.
+    ///
+    pub fn migrate_to_v2_block_at_registration() -> Weight {
+        let mut weight: Weight = T::DbWeight::get().reads( 1 );
+        if Pallet::<T>::on_chain_storage_version() != 1 { return weight }
+
+        // --- 1. Drain the uid keyed entries before writing the new layout under the same prefix.
+        let pallet_name: &[u8] = <Pallet<T> as PalletInfoAccess>::name().as_bytes();
+        let old_entries: Vec<(u16, u64)> = storage_key_iter::<u16, u64, Identity>( pallet_name, BLOCK_AT_REGISTRATION ).drain().collect();
+        weight = weight.saturating_add( T::DbWeight::get().reads_writes( old_entries.len() as u64, old_entries.len() as u64 ) );
+
+        // --- 2. Copy each block onto the networks that have the uid registered.
+        for ( netuid, uid, _ ) in <Keys<T> as IterableStorageDoubleMap<u16, u16, T::AccountId>>::iter() {
+            weight = weight.saturating_add( T::DbWeight::get().reads( 1 ) );
+            if let Some( ( _, block ) ) = old_entries.iter().find( |( old_uid, _ )| *old_uid == uid ) {
+                BlockAtRegistration::<T>::insert( netuid, uid, *block );
+                weight = weight.saturating_add( T::DbWeight::get().writes( 1 ) );
+            }
+        }
+
+        StorageVersion::new( 2 ).put::<Pallet<T>>();
+        weight.saturating_add( T::DbWeight::get().writes( 1 ) )
+    }
+
+    /// ---- Converts NeuronsToPruneAtNextEpoch from a single uid per network to the set of
+    /// uids replaced since the last epoch. Runs once, when the on chain storage version is 2.
+    ///
+    /// # Returns:
+    /// 	* 'Weight':
+    /// 		- The weight consumed by the migration.
+    ///
+    pub fn migrate_to_v3_neurons_to_prune() -> Weight {
+        let mut weight: Weight = T::DbWeight::get().reads( 1 );
+        if Pallet::<T>::on_chain_storage_version() != 2 { return weight }
+
+        NeuronsToPruneAtNextEpoch::<T>::translate::<u16, _>( |_, uid| {
+            weight = weight.saturating_add( T::DbWeight::get().reads_writes( 1, 1 ) );
+            Some( vec![ uid ] )
+        });
+
+        StorageVersion::new( 3 ).put::<Pallet<T>>();
+        weight.saturating_add( T::DbWeight::get().writes( 1 ) )
+    }
+
+    /// ---- Replaces the one to one Hotkeys and Coldkeys maps with Owner and OwnedHotkeys,
+    /// so that a coldkey can own many hotkeys. Every hotkey to coldkey link is carried over.
+    /// Runs once, when the on chain storage version is 3.
+    ///
+    /// # Returns:
+    /// 	* 'Weight':
+    /// 		- The weight consumed by the migration.
+    ///
+    pub fn migrate_to_v4_owned_hotkeys() -> Weight {
+        let mut weight: Weight = T::DbWeight::get().reads( 1 );
+        if Pallet::<T>::on_chain_storage_version() != 3 { return weight }
+
+        // --- 1. Move each hotkey to coldkey link into the new maps.
+        let pallet_name: &[u8] = <Pallet<T> as PalletInfoAccess>::name().as_bytes();
+        for ( hotkey, coldkey ) in storage_key_iter::<T::AccountId, T::AccountId, Blake2_128Concat>( pallet_name, HOTKEYS ).drain() {
+            Owner::<T>::insert( &hotkey, &coldkey );
+            OwnedHotkeys::<T>::mutate( &coldkey, |hotkeys| if !hotkeys.contains( &hotkey ) { hotkeys.push( hotkey.clone() ) } );
+            weight = weight.saturating_add( T::DbWeight::get().reads_writes( 2, 3 ) );
+        }
+
+        // --- 2. The reverse map holds nothing which is not in the forward map.
+        remove_storage_prefix( pallet_name, COLDKEYS, &[] );
+        weight = weight.saturating_add( T::DbWeight::get().writes( 1 ) );
+
+        StorageVersion::new( 4 ).put::<Pallet<T>>();
+        weight.saturating_add( T::DbWeight::get().writes( 1 ) )
+    }
+
+    /// ---- Accounts the stake of each hotkey to the coldkey which owns it, ahead of
+    /// nominators staking onto delegate hotkeys. Runs once, when the on chain storage version is 4.
+    ///
+    /// # Returns:
+    /// 	* 'Weight':
+    /// 		- The weight consumed by the migration.
+    ///
+    pub fn migrate_to_v5_coldkey_stake() -> Weight {
+        let mut weight: Weight = T::DbWeight::get().reads( 1 );
+        if Pallet::<T>::on_chain_storage_version() != 4 { return weight }
+
+        for ( hotkey, stake ) in <Stake<T> as IterableStorageMap<T::AccountId, u64>>::iter() {
+            weight = weight.saturating_add( T::DbWeight::get().reads( 2 ) );
+            if stake == 0 { continue }
+            ColdkeyStake::<T>::insert( &hotkey, Owner::<T>::get( &hotkey ), stake );
+            weight = weight.saturating_add( T::DbWeight::get().writes( 1 ) );
+        }
+
+        StorageVersion::new( 5 ).put::<Pallet<T>>();
+        weight.saturating_add( T::DbWeight::get().writes( 1 ) )
+    }
+
+    /// ---- Stores a take for each delegate in place of the delegate flag and sums the stake
+    /// accounted to coldkeys on each hotkey into DelegatedStake, the basis of the lazy emission
+    /// split. Runs once, when the on chain storage version is 5.
+    ///
+    /// # Returns:
+    /// 	* 'Weight':
+    /// 		- The weight consumed by the migration.
+    ///
+    pub fn migrate_to_v6_delegate_take() -> Weight {
+        let mut weight: Weight = T::DbWeight::get().reads( 1 );
+        if Pallet::<T>::on_chain_storage_version() != 5 { return weight }
+
+        // --- 1. Delegates start at the initial take.
+        Delegates::<T>::translate::<bool, _>( |_, is_delegate| {
+            weight = weight.saturating_add( T::DbWeight::get().reads_writes( 1, 1 ) );
+            if is_delegate { Some( T::InitialDelegateTake::get() ) } else { None }
+        });
+
+        // --- 2. Sum the stake of each coldkey on each hotkey.
+        for ( hotkey, _, stake ) in <ColdkeyStake<T> as IterableStorageDoubleMap<T::AccountId, T::AccountId, u64>>::iter() {
+            DelegatedStake::<T>::mutate( &hotkey, |delegated| *delegated = delegated.saturating_add( stake ) );
+            weight = weight.saturating_add( T::DbWeight::get().reads_writes( 2, 1 ) );
+        }
+
+        StorageVersion::new( 6 ).put::<Pallet<T>>();
+        weight.saturating_add( T::DbWeight::get().writes( 1 ) )
+    }
+
+    /// ---- Moves staked funds back into the coldkey accounts under the stake lock. Stake used to be
+    /// withdrawn from the coldkey, so the stake accounted to each coldkey, its pending rewards and
+    /// its unbonding chunks are minted back and locked. Hotkey stake not accounted to any coldkey
+    /// is minted to the owner of the hotkey. Stake which cannot be minted, because the coldkey
+    /// account does not exist and the amount is below the existential deposit, is removed together
+    /// with its share of TotalIssuance. Runs once, when the on chain storage version is 6.
+    ///
+    /// # Returns:
+    /// 	* 'Weight':
+    /// 		- The weight consumed by the migration.
+    ///
+    pub fn migrate_to_v7_stake_locks() -> Weight {
+        let mut weight: Weight = T::DbWeight::get().reads( 1 );
+        if Pallet::<T>::on_chain_storage_version() != 6 { return weight }
+
+        // --- 1. Lock the stake of each coldkey on each hotkey and settle its pending reward.
+        let coldkey_stakes: Vec<( T::AccountId, T::AccountId, u64 )> = <ColdkeyStake<T> as IterableStorageDoubleMap<T::AccountId, T::AccountId, u64>>::iter().collect();
+        for ( hotkey, coldkey, stake ) in coldkey_stakes {
+            let minted: u64 = Self::mint_locked_balance_to_coldkey_account( &coldkey, stake );
+            if minted < stake {
+                Self::decrease_stake_on_coldkey_hotkey_account( &coldkey, &hotkey, stake - minted );
+                Self::decrease_total_issuance( stake - minted );
+            }
+            Self::settle_pending_reward( &coldkey, &hotkey );
+            weight = weight.saturating_add( T::DbWeight::get().reads_writes( 8, 8 ) );
+        }
+
+        // --- 2. Lock the chunks each coldkey has unbonding.
+        let unbonding_chunks: Vec<( T::AccountId, Vec<UnbondingChunk<T::AccountId>> )> = <Unbonding<T> as IterableStorageMap<T::AccountId, Vec<UnbondingChunk<T::AccountId>>>>::iter().collect();
+        for ( coldkey, chunks ) in unbonding_chunks {
+            let unbonding: u64 = chunks.iter().fold( 0u64, |sum, chunk| sum.saturating_add( chunk.amount ) );
+            let minted: u64 = Self::mint_locked_balance_to_coldkey_account( &coldkey, unbonding );
+            if minted < unbonding {
+                Unbonding::<T>::remove( &coldkey );
+                Self::decrease_total_issuance( unbonding - minted );
+            }
+            weight = weight.saturating_add( T::DbWeight::get().reads_writes( 3, 4 ) );
+        }
+
+        // --- 3. Lock the hotkey stake not accounted to any coldkey under the owner of the hotkey.
+        let hotkey_stakes: Vec<( T::AccountId, u64 )> = <Stake<T> as IterableStorageMap<T::AccountId, u64>>::iter().collect();
+        for ( hotkey, stake ) in hotkey_stakes {
+            let accounted: u64 = <ColdkeyStake<T> as IterableStorageDoubleMap<T::AccountId, T::AccountId, u64>>::iter_prefix( &hotkey ).fold( 0u64, |sum, ( _, coldkey_stake )| sum.saturating_add( coldkey_stake ) );
+            let unaccounted: u64 = stake.saturating_sub( accounted );
+            weight = weight.saturating_add( T::DbWeight::get().reads( 2 ) );
+            if unaccounted == 0 { continue }
+            let owner: T::AccountId = Self::get_coldkey_for_hotkey( &hotkey );
+            let minted: u64 = Self::mint_locked_balance_to_coldkey_account( &owner, unaccounted );
+            Self::increase_stake_on_coldkey_hotkey_account( &owner, &hotkey, minted );
+            Self::remove_stake_from_hotkey_account( &hotkey, unaccounted );
+            Self::decrease_total_issuance( unaccounted - minted );
+            weight = weight.saturating_add( T::DbWeight::get().reads_writes( 10, 10 ) );
+        }
+
+        StorageVersion::new( 7 ).put::<Pallet<T>>();
+        weight.saturating_add( T::DbWeight::get().writes( 1 ) )
+    }
+
+    /// ---- Adds every network which already has a size to the NetworksAdded registry, keeping its
+    /// modality, so that networks created before the registry do not fail with NetworkDoesNotExist.
+    /// Runs once, when the on chain storage version is 7, before
+    /// the migrations which look up the existing networks in the registry.
+    ///
+    /// # Returns:
+    /// 	* 'Weight':
+    /// 		- The weight consumed by the migration.
+    ///
+    pub fn migrate_to_v8_networks_added() -> Weight {
+        let mut weight: Weight = T::DbWeight::get().reads( 1 );
+        if Pallet::<T>::on_chain_storage_version() != 7 { return weight }
+
+        // --- 1. Register each network which has a size.
+        for ( netuid, _ ) in <SubnetworkN<T> as IterableStorageMap<u16, u16>>::iter() {
+            NetworksAdded::<T>::insert( netuid, true );
+            NetworkModality::<T>::insert( netuid, NetworkModality::<T>::get( netuid ) );
+            weight = weight.saturating_add( T::DbWeight::get().reads_writes( 2, 2 ) );
+        }
+
+        StorageVersion::new( 8 ).put::<Pallet<T>>();
+        weight.saturating_add( T::DbWeight::get().writes( 1 ) )
+    }
+
+    /// ---- Copies the global MaxRegistrationsPerBlock onto every existing network now that it
+    /// is a per network map, and removes the global value and the global RegistrationsThisBlock
+    /// counter. The old values sit under the map prefixes and are not seen by the maps. The
+    /// counter is reset every block so it is not copied. Runs once, when the on chain storage
+    /// version is 8.
+    ///
+    /// # Returns:
+    /// 	* 'Weight':
+    /// 		- The weight consumed by the migration.
+    ///
+    pub fn migrate_to_v9_registrations_per_block() -> Weight {
+        let mut weight: Weight = T::DbWeight::get().reads( 1 );
+        if Pallet::<T>::on_chain_storage_version() != 8 { return weight }
+
+        // --- 1. Take the global values.
+        let pallet_name: &[u8] = <Pallet<T> as PalletInfoAccess>::name().as_bytes();
+        let max_registrations: Option<u16> = take_storage_value::<u16>( pallet_name, MAX_REGISTRATIONS_PER_BLOCK, &[] );
+        take_storage_value::<u16>( pallet_name, REGISTRATIONS_THIS_BLOCK, &[] );
+        weight = weight.saturating_add( T::DbWeight::get().reads_writes( 2, 2 ) );
+
+        // --- 2. Copy the limit onto each existing network.
+        if let Some( max_registrations ) = max_registrations {
+            for ( netuid, _ ) in <SubnetworkN<T> as IterableStorageMap<u16, u16>>::iter() {
+                MaxRegistrationsPerBlock::<T>::insert( netuid, max_registrations );
+                weight = weight.saturating_add( T::DbWeight::get().reads_writes( 1, 1 ) );
+            }
+        }
+
+        StorageVersion::new( 9 ).put::<Pallet<T>>();
+        weight.saturating_add( T::DbWeight::get().writes( 1 ) )
+    }
+
+    /// ---- Splits block emission evenly over the networks in the NetworksAdded registry when no
+    /// emission ratio has been set, since EmissionRatio defaults to 0 and would pay nothing to any
+    /// network once mechanism_step runs. Runs once, when the on chain storage version is 9.
+    ///
+    /// # Returns:
+    /// 	* 'Weight':
+    /// 		- The weight consumed by the migration.
+    ///
+    pub fn migrate_to_v10_emission_ratios() -> Weight {
+        let mut weight: Weight = T::DbWeight::get().reads( 1 );
+        if Pallet::<T>::on_chain_storage_version() != 9 { return weight }
+
+        // --- 1. Keep the ratios if sudo has already set any.
+        let netuids: Vec<u16> = <NetworksAdded<T> as IterableStorageMap<u16, bool>>::iter().filter( |( _, added )| *added ).map( |( netuid, _ )| netuid ).collect();
+        weight = weight.saturating_add( T::DbWeight::get().reads( netuids.len() as u64 + 1 ) );
+        if Self::calculate_emission_ratio_sum() == 0 && netuids.len() > 0 {
+
+            // --- 2. Give each network an equal share of u16::MAX.
+            let ratio: u16 = ( u16::MAX as usize / netuids.len() ) as u16;
+            for netuid in netuids.iter() {
+                EmissionRatio::<T>::insert( netuid, ratio );
+                weight = weight.saturating_add( T::DbWeight::get().writes( 1 ) );
+            }
+        }
+
+        StorageVersion::new( 10 ).put::<Pallet<T>>();
+        weight.saturating_add( T::DbWeight::get().writes( 1 ) )
+    }
+
+    /// ---- Copies the global AdjustmentInterval and TargetRegistrationsPerInterval onto every
+    /// existing network now that they are per network maps, and removes the global values. The
+    /// old values sit under the map prefixes and are not seen by the maps. Runs once, when the
+    /// on chain storage version is 10.
+    ///
+    /// # Returns:
+    /// 	* 'Weight':
+    /// 		- The weight consumed by the migration.
+    ///
+    pub fn migrate_to_v11_registration_intervals() -> Weight {
+        let mut weight: Weight = T::DbWeight::get().reads( 1 );
+        if Pallet::<T>::on_chain_storage_version() != 10 { return weight }
+
+        // --- 1. Take the global values.
+        let pallet_name: &[u8] = <Pallet<T> as PalletInfoAccess>::name().as_bytes();
+        let adjustment_interval: Option<u16> = take_storage_value::<u16>( pallet_name, ADJUSTMENT_INTERVAL, &[] );
+        let target_registrations: Option<u16> = take_storage_value::<u16>( pallet_name, TARGET_REGISTRATIONS_PER_INTERVAL, &[] );
+        weight = weight.saturating_add( T::DbWeight::get().reads_writes( 2, 2 ) );
+
+        // --- 2. Copy them onto each existing network.
+        for ( netuid, _ ) in <SubnetworkN<T> as IterableStorageMap<u16, u16>>::iter() {
+            if let Some( adjustment_interval ) = adjustment_interval {
+                AdjustmentInterval::<T>::insert( netuid, adjustment_interval );
+            }
+            if let Some( target_registrations ) = target_registrations {
+                TargetRegistrationsPerInterval::<T>::insert( netuid, target_registrations );
+            }
+            weight = weight.saturating_add( T::DbWeight::get().reads_writes( 1, 2 ) );
+        }
+
+        StorageVersion::new( 11 ).put::<Pallet<T>>();
+        weight.saturating_add( T::DbWeight::get().writes( 1 ) )
+    }
+
+    /// ---- Seeds LastUpdate with the upgrade block for every registered uid, so that neurons
+    /// registered before LastUpdate was tracked are not excluded from the epoch as inactive
+    /// straight after the upgrade. Runs once, when the on chain storage version is 11.
+    ///
+    /// # Returns:
+    /// 	* 'Weight':
+    /// 		- The weight consumed by the migration.
+    ///
+    pub fn migrate_to_v12_last_update() -> Weight {
+        let mut weight: Weight = T::DbWeight::get().reads( 1 );
+        if Pallet::<T>::on_chain_storage_version() != 11 { return weight }
+
+        // --- 1. Mark each registered uid as updated at the upgrade block.
+        let current_block: u64 = Self::get_current_block_as_u64();
+        for ( netuid, uid, _ ) in <Keys<T> as IterableStorageDoubleMap<u16, u16, T::AccountId>>::iter() {
+            LastUpdate::<T>::insert( netuid, uid, current_block );
+            weight = weight.saturating_add( T::DbWeight::get().reads_writes( 1, 1 ) );
+        }
+
+        StorageVersion::new( 12 ).put::<Pallet<T>>();
+        weight.saturating_add( T::DbWeight::get().writes( 1 ) )
+    }
+}
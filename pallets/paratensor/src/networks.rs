@@ -0,0 +1,185 @@
+use super::*;
+use frame_support::inherent::Vec;
+use frame_support::storage::IterableStorageDoubleMap;
+use frame_support::traits::Get;
+use frame_system::{ensure_root};
+
+impl<T: Config> Pallet<T> {
+
+    /// ---- The implementation for the extrinsic sudo_add_network.
+    ///
+    /// # Args:
+    /// 	* 'origin': (<T as frame_system::Config>Origin):
+    /// 		- Must be sudo.
+    ///
+    /// 	* 'netuid' (u16):
+    /// 		- The uid of the new network.
+    ///
+    /// 	* 'tempo' (u16):
+    /// 		- The number of steps between epochs on the network.
+    ///
+    /// 	* 'modality' (u16):
+    /// 		- The network modality, one of TEXT: 0, IMAGE: 1, TENSOR: 2.
+    ///
+    /// # Event:
+    /// 	* NetworkAdded;
+    /// 		- On successfully adding the network.
+    ///
+    /// # Raises:
+    /// 	* 'NetworkExist':
+    /// 		- The network has already been added.
+    ///
+    /// 	* 'InvalidModality':
+    /// 		- The modality is not one of the known modalities.
+    ///
+    /// 	* 'TempoOutOfRange':
+    /// 		- The tempo is outside [MinimumTempo, MaximumTempo].
+    ///
+    pub fn do_add_network( origin: T::Origin, netuid: u16, tempo: u16, modality: u16 ) -> dispatch::DispatchResult {
+
+        // --- 1. Ensure this is a sudo caller.
+        ensure_root( origin )?;
+
+        // --- 2. Ensure the network does not already exist.
+        ensure!( !Self::if_subnet_exist( netuid ), Error::<T>::NetworkExist );
+
+        // --- 3. Ensure the modality and tempo are valid.
+        ensure!( Self::if_modality_is_valid( modality ), Error::<T>::InvalidModality );
+        ensure!( tempo >= T::MinimumTempo::get() && tempo <= T::MaximumTempo::get(), Error::<T>::TempoOutOfRange );
+
+        // --- 4. Add the network to the registry.
+        NetworksAdded::<T>::insert( netuid, true );
+        NetworkModality::<T>::insert( netuid, modality );
+        Self::set_tempo( netuid, tempo );
+
+        // --- 5. Emit the tracking event.
+        Self::deposit_event( Event::NetworkAdded( netuid, modality ) );
+
+        // --- 6. Return ok.
+        Ok(())
+    }
+
+    /// ---- The implementation for the extrinsic sudo_remove_network. Every neuron registered on
+    /// the network is deregistered. Hotkeys which are not registered on any other network have
+    /// their stake returned to their coldkey, as on pruning.
+    ///
+    /// # Args:
+    /// 	* 'origin': (<T as frame_system::Config>Origin):
+    /// 		- Must be sudo.
+    ///
+    /// 	* 'netuid' (u16):
+    /// 		- The uid of the network to remove.
+    ///
+    /// # Event:
+    /// 	* NetworkRemoved;
+    /// 		- On successfully removing the network.
+    ///
+    /// # Raises:
+    /// 	* 'NetworkDoesNotExist':
+    /// 		- The network has not been added.
+    ///
+    pub fn do_remove_network( origin: T::Origin, netuid: u16 ) -> dispatch::DispatchResult {
+
+        // --- 1. Ensure this is a sudo caller.
+        ensure_root( origin )?;
+
+        // --- 2. Ensure the network exists.
+        ensure!( Self::if_subnet_exist( netuid ), Error::<T>::NetworkDoesNotExist );
+
+        // --- 3. Deregister the hotkeys on this network.
+        let hotkeys: Vec<(u16, T::AccountId)> = <Keys<T> as IterableStorageDoubleMap<u16, u16, T::AccountId>>::iter_prefix( netuid ).collect();
+        for ( _, hotkey ) in hotkeys {
+            let subnets_for_hotkey: Vec<u16> = Subnets::<T>::get( &hotkey );
+            if subnets_for_hotkey.len() == 1 && subnets_for_hotkey[0] == netuid {
+                // This is the only network the hotkey is on, its stake is queued for unbonding to the coldkeys.
+                Self::unstake_all_from_hotkey_account( &hotkey );
+                Self::remove_global_account( &hotkey );
+                Subnets::<T>::remove( &hotkey );
+            } else {
+                Self::decrement_subnets_for_hotkey( netuid, &hotkey );
+            }
+        }
+
+        // --- 4. Remove the network's neurons and consensus storage.
+        Keys::<T>::remove_prefix( netuid, None );
+        Uids::<T>::remove_prefix( netuid, None );
+        Weights::<T>::remove_prefix( netuid, None );
+        Bonds::<T>::remove_prefix( netuid, None );
+        S::<T>::remove_prefix( netuid, None );
+        Rank::<T>::remove_prefix( netuid, None );
+        Trust::<T>::remove_prefix( netuid, None );
+        Incentive::<T>::remove_prefix( netuid, None );
+        Consensus::<T>::remove_prefix( netuid, None );
+        Dividends::<T>::remove_prefix( netuid, None );
+        Emission::<T>::remove_prefix( netuid, None );
+        PrunningScores::<T>::remove_prefix( netuid, None );
+        BlockAtRegistration::<T>::remove_prefix( netuid, None );
+        LastUpdate::<T>::remove_prefix( netuid, None );
+        Axons::<T>::remove_prefix( netuid, None );
+        Prometheus::<T>::remove_prefix( netuid, None );
+        Active::<T>::remove( netuid );
+        ValidatorPermit::<T>::remove( netuid );
+        SubnetworkN::<T>::remove( netuid );
+        NeuronsToPruneAtNextEpoch::<T>::remove( netuid );
+
+        // --- 5. Remove the network's emission and registration state.
+        EmissionRatio::<T>::remove( netuid );
+        PendingEmission::<T>::remove( netuid );
+        StepsSinceLastEpoch::<T>::remove( netuid );
+        RegistrationsThisInterval::<T>::remove( netuid );
+        RegistrationsThisBlock::<T>::remove( netuid );
+
+        // --- 6. Reset the network's hyperparameters to their defaults.
+        Tempo::<T>::remove( netuid );
+        Difficulty::<T>::remove( netuid );
+        AdjustmentInterval::<T>::remove( netuid );
+        TargetRegistrationsPerInterval::<T>::remove( netuid );
+        MaxRegistrationsPerBlock::<T>::remove( netuid );
+        ActivityCutoff::<T>::remove( netuid );
+        Rho::<T>::remove( netuid );
+        Kappa::<T>::remove( netuid );
+        BondsMovingAverage::<T>::remove( netuid );
+        TrustThreshold::<T>::remove( netuid );
+        MaxAllowedValidators::<T>::remove( netuid );
+        MaxAllowedUids::<T>::remove( netuid );
+        MinAllowedWeights::<T>::remove( netuid );
+        MaxAllowedMaxMinRatio::<T>::remove( netuid );
+        ValidatorBatchSize::<T>::remove( netuid );
+        ValidatorSequenceLength::<T>::remove( netuid );
+        ValidatorEpochLen::<T>::remove( netuid );
+        ValidatorEpochsPerReset::<T>::remove( netuid );
+        IncentivePruningDenominator::<T>::remove( netuid );
+        StakePruningDenominator::<T>::remove( netuid );
+        StakePruningMin::<T>::remove( netuid );
+        ImmunityPeriod::<T>::remove( netuid );
+
+        // --- 7. Remove the network from the registry.
+        NetworksAdded::<T>::remove( netuid );
+        NetworkModality::<T>::remove( netuid );
+
+        // --- 8. Emit the tracking event.
+        Self::deposit_event( Event::NetworkRemoved( netuid ) );
+
+        // --- 9. Return ok.
+        Ok(())
+    }
+
+    /// ==========================
+	/// ==== Helper functions ====
+	/// ==========================
+
+    // --- Returns true if the network has been added.
+    pub fn if_subnet_exist( netuid: u16 ) -> bool {
+        return NetworksAdded::<T>::get( netuid );
+    }
+
+    // --- Returns true if the modality is one of TEXT: 0, IMAGE: 1, TENSOR: 2.
+    pub fn if_modality_is_valid( modality: u16 ) -> bool {
+        return modality <= 2;
+    }
+
+    // --- Returns the modality of the network.
+    pub fn get_network_modality( netuid: u16 ) -> u16 {
+        return NetworkModality::<T>::get( netuid );
+    }
+}
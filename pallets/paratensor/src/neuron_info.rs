@@ -0,0 +1,66 @@
+use super::*;
+use frame_support::inherent::Vec;
+use frame_support::RuntimeDebug;
+use codec::{Decode, Encode};
+use scale_info::TypeInfo;
+
+/// ---- A snapshot of the state held for a single neuron on a subnetwork.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct NeuronInfo<AccountId> {
+    pub hotkey: AccountId,
+    pub coldkey: AccountId,
+    pub uid: u16,
+    pub netuid: u16,
+    pub stake: u64,
+    pub rank: u16,
+    pub trust: u16,
+    pub consensus: u16,
+    pub incentive: u16,
+    pub dividends: u16,
+    pub emission: u64,
+    pub pruning_score: u16,
+    pub block_at_registration: u64,
+    pub last_update: u64,
+    pub active: bool,
+    pub validator_permit: bool,
+    pub weights: Vec<(u16, u16)>,
+    pub bonds: Vec<(u16, u16)>,
+}
+
+impl<T: Config> Pallet<T> {
+
+    /// ---- Returns the info of the neuron registered under uid on the network, or None
+    /// if the uid is not in use.
+    ///
+    /// # Args:
+    /// 	* 'netuid' (u16):
+    /// 		- The network to query.
+    ///
+    /// 	* 'uid' (u16):
+    /// 		- The uid of the neuron on the network.
+    ///
+    pub fn get_neuron_info( netuid: u16, uid: u16 ) -> Option<NeuronInfo<T::AccountId>> {
+        if !Self::is_subnetwork_uid_active( netuid, uid ) { return None }
+        let hotkey: T::AccountId = Self::get_hotkey_for_net_and_neuron( netuid, uid );
+        Some( NeuronInfo {
+            coldkey: Self::get_coldkey_for_hotkey( &hotkey ),
+            hotkey: hotkey,
+            uid: uid,
+            netuid: netuid,
+            stake: Self::get_neuron_stake_for_subnetwork( netuid, uid ),
+            rank: Self::get_ranks( netuid, uid ),
+            trust: Self::get_trust( netuid, uid ),
+            consensus: Self::get_consensus( netuid, uid ),
+            incentive: Self::get_incentives( netuid, uid ),
+            dividends: Self::get_dividends( netuid, uid ),
+            emission: Self::get_emission( netuid, uid ),
+            pruning_score: Self::get_prunning_score( netuid, uid ),
+            block_at_registration: Self::get_neuron_block_at_registration( netuid, uid ),
+            last_update: Self::get_last_update_for_uid( netuid, uid ),
+            active: Self::get_active( netuid ).get( uid as usize ).copied().unwrap_or( false ),
+            validator_permit: Self::get_validator_permit( netuid ).get( uid as usize ).copied().unwrap_or( false ),
+            weights: Self::get_weights_for_neuron( netuid, uid ),
+            bonds: Self::get_bonds_for_neuron( netuid, uid ),
+        } )
+    }
+}
@@ -43,9 +43,12 @@ impl<T: Config> Pallet<T> {
         //         - update appropriate parameters.
         //         -  add new neuron to neurons. hotkeys, and works
         //
+        // 0. check that the network exists.
+        ensure! (Self::if_subnet_exist( netuid ), Error::<T>::NetworkDoesNotExist);
+
         // 1. check registration per block 
-        let registrations_this_block: u16 = Self:: get_registrations_this_block();
-        ensure! (registrations_this_block < Self:: get_max_registratations_per_block(), Error::<T>::ToManyRegistrationsThisBlock); // Number of registrations this block exceeded.
+        let registrations_this_block: u16 = Self::get_registrations_this_block( netuid );
+        ensure! (registrations_this_block < Self::get_max_registratations_per_block( netuid ), Error::<T>::ToManyRegistrationsThisBlock); // Number of registrations this block exceeded.
         ensure! (!Uids::<T>::contains_key(&netuid, &hotkey), Error::<T>::AlreadyRegistered); // Hotkey has already registered.
 
         /* 2. Check block number validity. */
@@ -73,19 +76,21 @@ impl<T: Config> Pallet<T> {
         let max_allowed_uids: u16 = Self::get_max_allowed_uids(netuid); // Get uid limit.
         let neuron_count: u16 = Self::get_subnetwork_n(netuid); // Current number of uids for netuid network.
         let current_block: u64 = Self::get_current_block_as_u64();
-        //let immunity_period: u16 = Self::get_immunity_period(netuid); // Num blocks uid cannot be pruned since registration.
         if neuron_count < max_allowed_uids { 
 
-            // 7.b. NO:  The metagraph is not full and we simply increment the uid.
-            uid_to_set_in_metagraph = Self::get_next_uid();  
+            // 7.b. NO:  The metagraph is not full and we take the next uid on this network.
+            uid_to_set_in_metagraph = Self::get_next_uid( netuid );  
+            Self::add_subnetwork_account(netuid, uid_to_set_in_metagraph, &hotkey); // Uids, Keys, SubnetworkN
         } else { 
             // 7.a. YES:
-                // - compute the pruning score
-            let uid_to_prune: u16 = Self::get_neuron_to_prune(netuid); // neuron uid to prune
+                // - find the lowest scoring neuron outside of its immunity period.
+            let uid_to_prune: Option<u16> = Self::get_neuron_to_prune(netuid);
+            ensure! ( uid_to_prune.is_some(), Error::<T>::AllNeuronsImmune ); // Every neuron is still inside its immunity period.
+            let uid_to_prune: u16 = uid_to_prune.unwrap(); // neuron uid to prune
             uid_to_set_in_metagraph = uid_to_prune; 
             let hotkey_to_prune = Keys::<T>::get(netuid, uid_to_prune);
             //
-            let stake_to_remove: u64 = S::<T>::take(netuid, uid_to_prune); //remove hotkey stake for this network.
+            S::<T>::remove(netuid, uid_to_prune); //remove hotkey stake for this network.
             /* check if the hotkey is deregistred from all networks, 
             if so, then we need to transfer stake from hotkey to cold key */
             let vec_subnets_for_pruning_hotkey: Vec<u16> = Subnets::<T>::get(&hotkey_to_prune); // a list of subnets that hotkey is registered on.
@@ -93,20 +98,22 @@ impl<T: Config> Pallet<T> {
 
                 if vec_subnets_for_pruning_hotkey[0] == netuid {
                     // we need to remove all stakes since this hotkey is not staked in any other networks
-                    // These funds are deposited back into the coldkey account so that no funds are destroyed. 
+                    // These funds are queued for unbonding to the coldkey accounts so that no funds are destroyed
+                    // and the stake stays locked for the unbonding period.
                     //
-                    let coldkey_to_add_stake = Coldkeys::<T>::get(&hotkey_to_prune);
-                    Self::add_balance_to_coldkey_account( &coldkey_to_add_stake, Self::u64_to_balance(stake_to_remove).unwrap());
-                    Self::decrease_total_stake( stake_to_remove );
-                    Self::remove_global_stake(&hotkey_to_prune);
-                    Self::remove_stake_for_subnet(&hotkey_to_prune);
+                    // Each nominator is returned the stake it holds on the hotkey.
+                    Self::unstake_all_from_hotkey_account(&hotkey_to_prune);
                     //
-                    Self::remove_subnetwork_account(netuid, uid_to_set_in_metagraph); //UIds, Keys
-                    Self::remove_global_account(&hotkey); //Hotkeys, Coldkeys
+                    Self::remove_global_account(&hotkey_to_prune); //Owner, OwnedHotkeys
                     Subnets::<T>::remove(&hotkey_to_prune);
                     //
                 } 
-            } 
+            } else {
+                // The hotkey keeps its stake on the other networks it is registered on.
+                Self::decrement_subnets_for_hotkey(netuid, &hotkey_to_prune);
+            }
+            // Replace the hotkey in place at the pruned uid, the network size is unchanged.
+            Self::replace_subnetwork_account(netuid, uid_to_prune, &hotkey); // Uids, Keys
             // remove consensus storage for pruning uid
             // remove weights
             Self::remove_weights_from_subnet(netuid, uid_to_prune);
@@ -127,24 +134,27 @@ impl<T: Config> Pallet<T> {
             Self::remove_emission_from_subnet(netuid, uid_to_prune);
             // remove pruning score 
             Self::remove_pruning_score_from_subnet(netuid, uid_to_prune);
+            // remove served axon and prometheus endpoints
+            Axons::<T>::remove(netuid, &hotkey_to_prune);
+            Prometheus::<T>::remove(netuid, &hotkey_to_prune);
             //
             // Next we will add this prunned peer to NeuronsToPruneAtNextEpoch.
             // We record this set because we need to remove all bonds owned in this uid.
             // neuron.bonds records all bonds this peer owns which will be removed by default. 
             // However there are other peers with bonds in this peer, these need to be cleared as well. 
-            NeuronsToPruneAtNextEpoch::<T>::insert( netuid, uid_to_prune ); // Subtrate does not contain a set storage item.
+            Self::add_uid_to_prune( netuid, uid_to_prune );
         }
         
         // next, we add new registered node to all structures
-        BlockAtRegistration::<T>::insert( uid_to_set_in_metagraph, current_block ); // Set immunity momment. 
+        BlockAtRegistration::<T>::insert( netuid, uid_to_set_in_metagraph, current_block ); // Set immunity momment. 
+        LastUpdate::<T>::insert( netuid, uid_to_set_in_metagraph, current_block ); // Active until the activity cutoff passes.
         Self::add_global_account(&hotkey, &coldkey);
         Self::increment_subnets_for_hotkey(netuid, &hotkey);
-        Self::add_subnetwork_account(netuid, uid_to_set_in_metagraph, &hotkey);
         Self::add_hotkey_stake_for_network(netuid, &hotkey);
         UsedWork::<T>::insert( &work.clone(), current_block ); // Add the work to current + block. So we can prune at a later date.
         // --- Update avg registrations per 1000 block.
         RegistrationsThisInterval::<T>::mutate( netuid, |val| *val += 1 );
-        RegistrationsThisBlock::<T>::mutate( |val| *val += 1 );
+        RegistrationsThisBlock::<T>::mutate( netuid, |val| *val += 1 );
         //
         Self::deposit_event(Event::NeuronRegistered( uid_to_set_in_metagraph ));
         //
@@ -0,0 +1,212 @@
+use super::*;
+
+impl<T: Config> Pallet<T> {
+
+    /// ---- The implementation for the extrinsic serve_axon.
+    ///
+    /// # Args:
+    /// 	* 'origin': (<T as frame_system::Config>Origin):
+    /// 		- The signature of the calling hotkey.
+    ///
+    /// 	* 'netuid' (u16):
+    /// 		- The network the axon is served on.
+    ///
+    /// 	* 'version' (u32):
+    /// 		- The axon version.
+    ///
+    /// 	* 'ip' (u128):
+    /// 		- The u128 encoded ip address of type 6 or 4.
+    ///
+    /// 	* 'port' (u16):
+    /// 		- The port number where this neuron receives RPC requests.
+    ///
+    /// 	* 'ip_type' (u8):
+    /// 		- The ip type one of (4,6).
+    ///
+    /// 	* 'modality' (u8):
+    /// 		- The neuron modality type.
+    ///
+    /// # Event:
+    /// 	* AxonServed;
+    /// 		- On successfully serving the axon.
+    ///
+    /// # Raises:
+    /// 	* 'NetworkDoesNotExist':
+    /// 		- The network has not been added.
+    ///
+    /// 	* 'NotRegistered':
+    /// 		- The calling hotkey is not registered on the network.
+    ///
+    /// 	* 'InvalidIpType':
+    /// 		- The ip type is not 4 or 6.
+    ///
+    /// 	* 'InvalidIpAddress':
+    /// 		- The ip address is not valid for the ip type.
+    ///
+    /// 	* 'InvalidModality':
+    /// 		- The modality is not one of TEXT: 0, IMAGE: 1, TENSOR: 2.
+    ///
+    /// 	* 'ServingRateLimitExceeded':
+    /// 		- The hotkey served an axon on any network within the last ServingRateLimit blocks.
+    ///
+    pub fn do_serve_axon( origin: T::Origin, netuid: u16, version: u32, ip: u128, port: u16, ip_type: u8, modality: u8 ) -> dispatch::DispatchResult {
+
+        // --- 1. Check the caller's signature. This is the hotkey of a registered account.
+        let hotkey = ensure_signed( origin )?;
+
+        // --- 2. Check that the network exists and the hotkey is registered on it.
+        ensure!( Self::if_subnet_exist( netuid ), Error::<T>::NetworkDoesNotExist );
+        ensure!( Self::is_hotkey_subnetwork_active( netuid, &hotkey ), Error::<T>::NotRegistered );
+
+        // --- 3. Check the ip type and address.
+        ensure!( Self::is_valid_ip_type( ip_type ), Error::<T>::InvalidIpType );
+        ensure!( Self::is_valid_ip_address( ip_type, ip ), Error::<T>::InvalidIpAddress );
+        ensure!( Self::if_modality_is_valid( modality as u16 ), Error::<T>::InvalidModality );
+
+        // --- 4. Check the hotkey is not serving faster than the rate limit on any network.
+        let current_block: u64 = Self::get_current_block_as_u64();
+        let prev_block: Option<u64> = LastServedAxon::<T>::get( &hotkey );
+        ensure!( Self::serving_passes_rate_limit( prev_block, current_block ), Error::<T>::ServingRateLimitExceeded );
+
+        // --- 5. Sink the axon info and the serving block to storage.
+        LastServedAxon::<T>::insert( &hotkey, current_block );
+        Axons::<T>::insert( netuid, hotkey.clone(), AxonInfo {
+            block: current_block,
+            version: version,
+            ip: ip,
+            port: port,
+            ip_type: ip_type,
+            modality: modality,
+        });
+
+        // --- 6. Emit the tracking event.
+        Self::deposit_event( Event::AxonServed( netuid, hotkey ) );
+
+        // --- 7. Return ok.
+        Ok(())
+    }
+
+    /// ---- The implementation for the extrinsic serve_prometheus.
+    ///
+    /// # Args:
+    /// 	* 'origin': (<T as frame_system::Config>Origin):
+    /// 		- The signature of the calling hotkey.
+    ///
+    /// 	* 'netuid' (u16):
+    /// 		- The network the endpoint is served on.
+    ///
+    /// 	* 'version' (u32):
+    /// 		- The prometheus version.
+    ///
+    /// 	* 'ip' (u128):
+    /// 		- The u128 encoded ip address of type 6 or 4.
+    ///
+    /// 	* 'port' (u16):
+    /// 		- The port number where this neuron serves its metrics.
+    ///
+    /// 	* 'ip_type' (u8):
+    /// 		- The ip type one of (4,6).
+    ///
+    /// # Event:
+    /// 	* PrometheusServed;
+    /// 		- On successfully serving the prometheus endpoint.
+    ///
+    /// # Raises:
+    /// 	* 'NetworkDoesNotExist':
+    /// 		- The network has not been added.
+    ///
+    /// 	* 'NotRegistered':
+    /// 		- The calling hotkey is not registered on the network.
+    ///
+    /// 	* 'InvalidIpType':
+    /// 		- The ip type is not 4 or 6.
+    ///
+    /// 	* 'InvalidIpAddress':
+    /// 		- The ip address is not valid for the ip type.
+    ///
+    /// 	* 'ServingRateLimitExceeded':
+    /// 		- The hotkey served a prometheus endpoint on any network within the last ServingRateLimit blocks.
+    ///
+    pub fn do_serve_prometheus( origin: T::Origin, netuid: u16, version: u32, ip: u128, port: u16, ip_type: u8 ) -> dispatch::DispatchResult {
+
+        // --- 1. Check the caller's signature. This is the hotkey of a registered account.
+        let hotkey = ensure_signed( origin )?;
+
+        // --- 2. Check that the network exists and the hotkey is registered on it.
+        ensure!( Self::if_subnet_exist( netuid ), Error::<T>::NetworkDoesNotExist );
+        ensure!( Self::is_hotkey_subnetwork_active( netuid, &hotkey ), Error::<T>::NotRegistered );
+
+        // --- 3. Check the ip type and address.
+        ensure!( Self::is_valid_ip_type( ip_type ), Error::<T>::InvalidIpType );
+        ensure!( Self::is_valid_ip_address( ip_type, ip ), Error::<T>::InvalidIpAddress );
+
+        // --- 4. Check the hotkey is not serving faster than the rate limit on any network.
+        let current_block: u64 = Self::get_current_block_as_u64();
+        let prev_block: Option<u64> = LastServedPrometheus::<T>::get( &hotkey );
+        ensure!( Self::serving_passes_rate_limit( prev_block, current_block ), Error::<T>::ServingRateLimitExceeded );
+
+        // --- 5. Sink the prometheus info and the serving block to storage.
+        LastServedPrometheus::<T>::insert( &hotkey, current_block );
+        Prometheus::<T>::insert( netuid, hotkey.clone(), PrometheusInfo {
+            block: current_block,
+            version: version,
+            ip: ip,
+            port: port,
+            ip_type: ip_type,
+        });
+
+        // --- 6. Emit the tracking event.
+        Self::deposit_event( Event::PrometheusServed( netuid, hotkey ) );
+
+        // --- 7. Return ok.
+        Ok(())
+    }
+
+    /// ==========================
+	/// ==== Helper functions ====
+	/// ==========================
+
+    // --- Returns true if the ip type is 4 or 6.
+    pub fn is_valid_ip_type( ip_type: u8 ) -> bool {
+        return ip_type == 4 || ip_type == 6;
+    }
+
+    // --- Returns true if the address is non zero and fits the width of its ip type.
+    pub fn is_valid_ip_address( ip_type: u8, addr: u128 ) -> bool {
+        if !Self::is_valid_ip_type( ip_type ) { return false; }
+        if addr == 0 { return false; }
+        if ip_type == 4 && addr > u32::MAX as u128 { return false; }
+        return true;
+    }
+
+    // --- Returns true if nothing was served before or ServingRateLimit blocks have passed since.
+    pub fn serving_passes_rate_limit( prev_block: Option<u64>, current_block: u64 ) -> bool {
+        match prev_block {
+            Some( block ) => current_block.saturating_sub( block ) >= Self::get_serving_rate_limit(),
+            None => true,
+        }
+    }
+
+    // --- Returns the most recent block the hotkey served an axon on, across all networks.
+    pub fn get_last_axon_block( hotkey: &T::AccountId ) -> Option<u64> {
+        return LastServedAxon::<T>::get( hotkey );
+    }
+
+    // --- Returns the most recent block the hotkey served a prometheus endpoint on, across all networks.
+    pub fn get_last_prometheus_block( hotkey: &T::AccountId ) -> Option<u64> {
+        return LastServedPrometheus::<T>::get( hotkey );
+    }
+
+    pub fn get_axon_info( netuid: u16, hotkey: &T::AccountId ) -> Option<AxonInfo> {
+        return Axons::<T>::get( netuid, hotkey );
+    }
+    pub fn get_prometheus_info( netuid: u16, hotkey: &T::AccountId ) -> Option<PrometheusInfo> {
+        return Prometheus::<T>::get( netuid, hotkey );
+    }
+    pub fn get_serving_rate_limit() -> u64 {
+        return ServingRateLimit::<T>::get();
+    }
+    pub fn set_serving_rate_limit( serving_rate_limit: u64 ) {
+        ServingRateLimit::<T>::put( serving_rate_limit );
+    }
+}
@@ -1,5 +1,13 @@
 use super::*;
 use frame_support::sp_std::vec;
+use frame_support::traits::{Get, Imbalance};
+use sp_core::U256;
+
+/// Fixed point scale of RewardPerShare.
+pub const REWARD_PER_SHARE_SCALE: u128 = 1_000_000_000_000;
+
+/// Identifier of the balance lock holding stake in the coldkey accounts.
+pub const STAKE_LOCK_ID: LockIdentifier = *b"paratens";
 
 impl<T: Config> Pallet<T> {
 
@@ -9,9 +17,9 @@ impl<T: Config> Pallet<T> {
      /* TO DO:
      1. heck the transaction is signed by the caller and retrieve the T::AccountId coldkey. 
      2. Check if the hotkey is active
-     3. check that the hotkey is linked to the calling cold key, otherwise throw a NonAssociatedColdKey error.
-     4. check that the calling coldkey contains enough funds to create the staking transaction.
-     5. transfer stake from coldkey to hotkey
+     3. check that the hotkey is owned by the calling cold key or is a delegate, otherwise throw a NonAssociatedColdKey error.
+     4. check that the calling coldkey contains enough unlocked funds to create the staking transaction.
+     5. lock the stake in the coldkey account and add it to the hotkey
      6. emit the staking event.*/
 
      pub fn do_add_stake(origin: T::Origin, hotkey: T::AccountId, stake_to_be_added: u64) -> dispatch::DispatchResult
@@ -22,20 +30,20 @@ impl<T: Config> Pallet<T> {
          //2. Check if the hotkey is active
          ensure!(Self::is_hotkey_active(&hotkey), Error::<T>::NotRegistered);
  
-         //3. We check that the hotkey is linked to the calling cold key, 
+         //3. We check that the hotkey is owned by the calling cold key or accepts nominations,
          // otherwise throw a NonAssociatedColdKey error.
-         ensure!(Self::hotkey_belongs_to_coldkey(&hotkey, &coldkey), Error::<T>::NonAssociatedColdKey);
+         ensure!(Self::coldkey_can_stake_to_hotkey(&coldkey, &hotkey), Error::<T>::NonAssociatedColdKey);
  
-         //4. We check that the calling coldkey contains enough funds to
+         //4. We check that the calling coldkey contains enough unlocked funds to
          // create the staking transaction.
          let stake_as_balance = Self::u64_to_balance(stake_to_be_added);
          ensure!(stake_as_balance.is_some(), Error::<T>::CouldNotConvertToBalance);
  
-         ensure!(Self::can_remove_balance_from_coldkey_account(&coldkey, stake_as_balance.unwrap()), Error::<T>::NotEnoughBalanceToStake);
+         ensure!(Self::can_lock_balance_on_coldkey_account(&coldkey, stake_to_be_added), Error::<T>::NotEnoughBalanceToStake);
 
-         //5. Transfer stake from coldkey to hotkey
-         ensure!(Self::remove_balance_from_coldkey_account(&coldkey, stake_as_balance.unwrap()) == true, Error::<T>::BalanceWithdrawalError);
-         Self::add_stake_to_neuron_hotkey_account(&hotkey, stake_to_be_added);
+         //5. Lock the stake in the coldkey account and add it to the hotkey
+         Self::lock_balance_on_coldkey_account(&coldkey, stake_to_be_added);
+         Self::increase_stake_on_coldkey_hotkey_account(&coldkey, &hotkey, stake_to_be_added);
  
          //6. Emit the staking event.
          Self::deposit_event(Event::StakeAdded(hotkey, stake_to_be_added));
@@ -52,13 +60,15 @@ impl<T: Config> Pallet<T> {
     /// Generally, this function works as follows
     /// 1) A Check is performed to see if the hotkey is active (ie, the node using the key is subscribed)
     /// 2) If these checks pass, inflation is emitted to the nodes' peers
-    /// 3) If the account has enough stake, the requested amount it transferred to the coldkey account
+    /// 3) If the account has enough stake, the requested amount is queued for the coldkey until the unbonding period has passed
     /// 4) The total amount of stake is reduced after transfer is complete
     ///
     /// It throws the following errors if there is something wrong
     /// - NotRegistered : The suplied hotkey is not in use. This ususally means a node that uses this key has not subscribed yet, or has unsubscribed
-    /// - NonAssociatedColdKey : The supplied hotkey account id is not subscribed using the supplied cold key
-    /// - NotEnoughStaketoWithdraw : The ammount of stake available in the hotkey account is lower than the requested amount
+    /// - NonAssociatedColdKey : The supplied cold key does not own the hotkey and the hotkey is not a delegate
+    /// - NotEnoughStaketoWithdraw : The ammount of stake the cold key holds on the hotkey is lower than the requested amount
+    /// - StakeToWithdrawIsZero : The requested amount is zero
+    /// - TooManyUnbondingChunks : The cold key already has the maximum number of chunks unbonding
     /// - CouldNotConvertToBalance : A conversion error occured while converting stake from u64 to Balance
     ///
     pub fn do_remove_stake(origin: T::Origin, hotkey: T::AccountId, stake_to_be_removed: u64) -> dispatch::DispatchResult {
@@ -70,28 +80,285 @@ impl<T: Config> Pallet<T> {
         // ---- check if hotkey is active
         ensure!(Self::is_hotkey_active(&hotkey), Error::<T>::NotRegistered);
 
-        // ---- We check that the hotkey is linked to the calling cold key, otherwise throw a NonAssociatedColdKey error.
-        ensure!(Self::hotkey_belongs_to_coldkey(&hotkey, &coldkey), Error::<T>::NonAssociatedColdKey);
+        // ---- We check that the hotkey is owned by the calling cold key or accepts nominations, otherwise throw a NonAssociatedColdKey error.
+        ensure!(Self::coldkey_can_stake_to_hotkey(&coldkey, &hotkey), Error::<T>::NonAssociatedColdKey);
 
-        // ---- We check that the hotkey has enough stake to withdraw
+        // ---- We check that the coldkey holds enough stake on the hotkey to withdraw
         // and then withdraw from the account.
-        ensure!(Self::has_enough_stake(&hotkey, stake_to_be_removed), Error::<T>::NotEnoughStaketoWithdraw);
+        ensure!(stake_to_be_removed > 0, Error::<T>::StakeToWithdrawIsZero);
+        ensure!(Self::has_enough_stake(&coldkey, &hotkey, stake_to_be_removed), Error::<T>::NotEnoughStaketoWithdraw);
         let stake_to_be_added_as_currency = Self::u64_to_balance(stake_to_be_removed);
         ensure!(stake_to_be_added_as_currency.is_some(), Error::<T>::CouldNotConvertToBalance);
 
-        // --- We perform the withdrawl by converting the stake to a u64 balance
-        // and deposit the balance into the coldkey account. If the coldkey account
-        // does not exist it is created.
-        Self::remove_stake_from_hotkey_account(&hotkey, stake_to_be_removed);
-        Self::add_balance_to_coldkey_account(&coldkey, stake_to_be_added_as_currency.unwrap());
+        // ---- We check that the coldkey has room in its unbonding queue.
+        ensure!(Self::can_add_unbonding_chunk(&coldkey), Error::<T>::TooManyUnbondingChunks);
 
-        // ---- Emit the unstaking event.
-        Self::deposit_event(Event::StakeRemoved(hotkey, stake_to_be_removed));
+        // --- We perform the withdrawl by moving the stake into the unbonding queue
+        // of the coldkey. The balance stays locked until withdraw_unbonded releases
+        // it once the unbonding period has passed.
+        Self::decrease_stake_on_coldkey_hotkey_account(&coldkey, &hotkey, stake_to_be_removed);
+        let unlock_block: u64 = Self::add_unbonding_chunk(&coldkey, &hotkey, stake_to_be_removed);
+
+        // ---- Emit the unstaking events.
+        Self::deposit_event(Event::StakeRemoved(hotkey.clone(), stake_to_be_removed));
+        Self::deposit_event(Event::StakeUnbonding(coldkey, hotkey, stake_to_be_removed, unlock_block));
 
         // --- Done and ok.
         Ok(())
     }
 
+    /// ---- The implementation for the extrinsic become_delegate.
+    ///
+    /// # Args:
+    /// 	* 'origin': (<T as frame_system::Config>Origin):
+    /// 		- The signature of the coldkey which owns the hotkey.
+    ///
+    /// 	* 'hotkey' (T::AccountId):
+    /// 		- The hotkey to make a delegate.
+    ///
+    /// # Event:
+    /// 	* DelegateAdded;
+    /// 		- On successfully making the hotkey a delegate.
+    ///
+    /// # Raises:
+    /// 	* 'NotRegistered':
+    /// 		- The hotkey is not registered on any network.
+    ///
+    /// 	* 'NonAssociatedColdKey':
+    /// 		- The calling coldkey does not own the hotkey.
+    ///
+    /// 	* 'AlreadyDelegate':
+    /// 		- The hotkey is already a delegate.
+    ///
+    pub fn do_become_delegate( origin: T::Origin, hotkey: T::AccountId ) -> dispatch::DispatchResult {
+
+        // --- 1. Check the caller's signature. This is the coldkey which owns the hotkey.
+        let coldkey = ensure_signed( origin )?;
+
+        // --- 2. Check the hotkey is registered and owned by the caller.
+        ensure!( Self::is_hotkey_active( &hotkey ), Error::<T>::NotRegistered );
+        ensure!( Self::hotkey_belongs_to_coldkey( &hotkey, &coldkey ), Error::<T>::NonAssociatedColdKey );
+
+        // --- 3. Check the hotkey is not already a delegate.
+        ensure!( !Self::hotkey_is_delegate( &hotkey ), Error::<T>::AlreadyDelegate );
+
+        // --- 4. Open the hotkey to nominations at the initial take.
+        Delegates::<T>::insert( &hotkey, T::InitialDelegateTake::get() );
+
+        // --- 5. Emit the tracking event.
+        Self::deposit_event( Event::DelegateAdded( coldkey, hotkey ) );
+
+        // --- 6. Return ok.
+        Ok(())
+    }
+
+    /// ---- The implementation for the extrinsic set_delegate_take.
+    ///
+    /// # Args:
+    /// 	* 'origin': (<T as frame_system::Config>Origin):
+    /// 		- The signature of the coldkey which owns the hotkey.
+    ///
+    /// 	* 'hotkey' (T::AccountId):
+    /// 		- The delegate hotkey.
+    ///
+    /// 	* 'take' (u16):
+    /// 		- The proportion of u16::MAX of the hotkey's emission kept by its owner.
+    ///
+    /// # Event:
+    /// 	* DelegateTakeSet;
+    /// 		- On successfully setting the take.
+    ///
+    /// # Raises:
+    /// 	* 'NonAssociatedColdKey':
+    /// 		- The calling coldkey does not own the hotkey.
+    ///
+    /// 	* 'NotADelegate':
+    /// 		- The hotkey is not a delegate.
+    ///
+    /// 	* 'DelegateTakeOutOfRange':
+    /// 		- The take is outside [MinimumDelegateTake, MaximumDelegateTake].
+    ///
+    pub fn do_set_delegate_take( origin: T::Origin, hotkey: T::AccountId, take: u16 ) -> dispatch::DispatchResult {
+
+        // --- 1. Check the caller's signature. This is the coldkey which owns the hotkey.
+        let coldkey = ensure_signed( origin )?;
+
+        // --- 2. Check the hotkey is a delegate owned by the caller.
+        ensure!( Self::hotkey_belongs_to_coldkey( &hotkey, &coldkey ), Error::<T>::NonAssociatedColdKey );
+        ensure!( Self::hotkey_is_delegate( &hotkey ), Error::<T>::NotADelegate );
+
+        // --- 3. Check the take is within bounds.
+        ensure!( take >= T::MinimumDelegateTake::get() && take <= T::MaximumDelegateTake::get(), Error::<T>::DelegateTakeOutOfRange );
+
+        // --- 4. Set the take, it applies to emission from now on.
+        Delegates::<T>::insert( &hotkey, take );
+
+        // --- 5. Emit the tracking event.
+        Self::deposit_event( Event::DelegateTakeSet( hotkey, take ) );
+
+        // --- 6. Return ok.
+        Ok(())
+    }
+
+    /// Returns true if the hotkey accepts stake from any coldkey.
+    ///
+    pub fn hotkey_is_delegate(hotkey: &T::AccountId) -> bool {
+        return Delegates::<T>::contains_key(hotkey);
+    }
+
+    /// Returns the take of the delegate hotkey.
+    ///
+    pub fn get_delegate_take(hotkey: &T::AccountId) -> u16 {
+        return Delegates::<T>::get(hotkey);
+    }
+
+    /// Credits emission to the hotkey. The emission of a delegate is split lazily: the owner's
+    /// take is credited to the owner's stake and the remainder is added to the hotkey's reward
+    /// per share, which each coldkey collects when its stake on the hotkey next changes.
+    /// Emission is minted into the coldkey account under the stake lock once it is credited.
+    /// Returns the amount credited, which is less than the emission when the owner's share
+    /// could not be minted.
+    ///
+    pub fn emit_inflation_through_hotkey_account(hotkey: &T::AccountId, emission: u64) -> u64 {
+        let owner: T::AccountId = Self::get_coldkey_for_hotkey(hotkey);
+        let delegated_stake: u64 = DelegatedStake::<T>::get(hotkey);
+        if !Self::hotkey_is_delegate(hotkey) || delegated_stake == 0 {
+            let minted: u64 = Self::mint_locked_balance_to_coldkey_account(&owner, emission);
+            Self::increase_stake_on_coldkey_hotkey_account(&owner, hotkey, minted);
+            return minted;
+        }
+        let take: u64 = (emission as u128 * Self::get_delegate_take(hotkey) as u128 / u16::MAX as u128) as u64;
+        let remaining: u64 = emission.saturating_sub(take);
+        RewardPerShare::<T>::mutate(hotkey, |reward_per_share| {
+            *reward_per_share = reward_per_share.saturating_add(remaining as u128 * REWARD_PER_SHARE_SCALE / delegated_stake as u128)
+        });
+        Self::add_stake_to_neuron_hotkey_account(hotkey, remaining);
+        let mut minted: u64 = 0;
+        if take > 0 {
+            minted = Self::mint_locked_balance_to_coldkey_account(&owner, take);
+            Self::increase_stake_on_coldkey_hotkey_account(&owner, hotkey, minted);
+        }
+        remaining.saturating_add(minted)
+    }
+
+    /// Returns the reward accrued by the stake at the passed reward per share.
+    ///
+    pub fn accrued_reward(stake: u64, reward_per_share: u128) -> u128 {
+        let accrued: U256 = U256::from(stake) * U256::from(reward_per_share) / U256::from(REWARD_PER_SHARE_SCALE);
+        if accrued > U256::from(u128::MAX) { u128::MAX } else { accrued.as_u128() }
+    }
+
+    /// Returns the emission owed to the coldkey on the hotkey which is not yet part of its stake.
+    ///
+    pub fn get_pending_reward(coldkey: &T::AccountId, hotkey: &T::AccountId) -> u64 {
+        let accrued: u128 = Self::accrued_reward(ColdkeyStake::<T>::get(hotkey, coldkey), RewardPerShare::<T>::get(hotkey));
+        accrued.saturating_sub(RewardDebt::<T>::get(hotkey, coldkey)).min(u64::MAX as u128) as u64
+    }
+
+    /// Moves the pending reward of the coldkey on the hotkey into its stake. The reward is
+    /// minted into the coldkey account under the stake lock. Reward which could not be minted
+    /// is removed from the hotkey stake and from the total issuance.
+    ///
+    pub fn settle_pending_reward(coldkey: &T::AccountId, hotkey: &T::AccountId) {
+        let pending: u64 = Self::get_pending_reward(coldkey, hotkey);
+        if pending > 0 {
+            let minted: u64 = Self::mint_locked_balance_to_coldkey_account(coldkey, pending);
+            ColdkeyStake::<T>::mutate(hotkey, coldkey, |stake| *stake = stake.saturating_add(minted));
+            DelegatedStake::<T>::mutate(hotkey, |stake| *stake = stake.saturating_add(minted));
+            if minted < pending {
+                Self::remove_stake_from_hotkey_account(hotkey, pending - minted);
+                Self::decrease_total_issuance(pending - minted);
+            }
+        }
+        Self::reset_reward_debt(coldkey, hotkey);
+    }
+
+    /// Marks the reward accrued so far by the coldkey's stake on the hotkey as credited.
+    ///
+    pub fn reset_reward_debt(coldkey: &T::AccountId, hotkey: &T::AccountId) {
+        let stake: u64 = ColdkeyStake::<T>::get(hotkey, coldkey);
+        if stake == 0 { RewardDebt::<T>::remove(hotkey, coldkey); return; }
+        RewardDebt::<T>::insert(hotkey, coldkey, Self::accrued_reward(stake, RewardPerShare::<T>::get(hotkey)));
+    }
+
+    /// Returns true if the coldkey may add and remove stake on the hotkey, either as its owner
+    /// or as a nominator of a delegate.
+    ///
+    pub fn coldkey_can_stake_to_hotkey(coldkey: &T::AccountId, hotkey: &T::AccountId) -> bool {
+        return Self::hotkey_belongs_to_coldkey(hotkey, coldkey) || Self::hotkey_is_delegate(hotkey);
+    }
+
+    /// Returns the stake the coldkey holds on the hotkey, including its pending reward.
+    ///
+    pub fn get_stake_for_coldkey_and_hotkey(coldkey: &T::AccountId, hotkey: &T::AccountId) -> u64 {
+        return ColdkeyStake::<T>::get(hotkey, coldkey).saturating_add(Self::get_pending_reward(coldkey, hotkey));
+    }
+
+    /// Increases the stake the coldkey holds on the hotkey, together with the hotkey,
+    /// subnetwork and total stake.
+    ///
+    pub fn increase_stake_on_coldkey_hotkey_account(coldkey: &T::AccountId, hotkey: &T::AccountId, amount: u64) {
+        Self::settle_pending_reward(coldkey, hotkey);
+        ColdkeyStake::<T>::mutate(hotkey, coldkey, |stake| *stake = stake.saturating_add(amount));
+        DelegatedStake::<T>::mutate(hotkey, |stake| *stake = stake.saturating_add(amount));
+        Self::reset_reward_debt(coldkey, hotkey);
+        Self::add_stake_to_neuron_hotkey_account(hotkey, amount);
+    }
+
+    /// Decreases the stake the coldkey holds on the hotkey, together with the hotkey,
+    /// subnetwork and total stake. The caller must check the coldkey holds enough stake.
+    ///
+    pub fn decrease_stake_on_coldkey_hotkey_account(coldkey: &T::AccountId, hotkey: &T::AccountId, amount: u64) {
+        Self::settle_pending_reward(coldkey, hotkey);
+        let coldkey_stake: u64 = ColdkeyStake::<T>::get(hotkey, coldkey);
+        debug_assert!(coldkey_stake >= amount);
+        if coldkey_stake <= amount { ColdkeyStake::<T>::remove(hotkey, coldkey); } else { ColdkeyStake::<T>::insert(hotkey, coldkey, coldkey_stake - amount); }
+        DelegatedStake::<T>::mutate(hotkey, |stake| *stake = stake.saturating_sub(amount));
+        Self::reset_reward_debt(coldkey, hotkey);
+        Self::remove_stake_from_hotkey_account(hotkey, amount);
+    }
+
+    /// Moves the stake of every coldkey on the hotkey, together with its pending reward, into the
+    /// coldkey's unbonding queue and clears the hotkey stake. The balance stays locked until
+    /// withdraw_unbonded releases it once the unbonding period has passed. Stake which is not
+    /// accounted to any coldkey is queued for the owner. Called when the hotkey is deregistered
+    /// from its last network.
+    ///
+    pub fn unstake_all_from_hotkey_account(hotkey: &T::AccountId) {
+        let hotkey_stake: u64 = Stake::<T>::get(hotkey);
+        let mut returned: u64 = 0;
+        let reward_per_share: u128 = RewardPerShare::<T>::take(hotkey);
+        for (coldkey, principal) in ColdkeyStake::<T>::drain_prefix(hotkey) {
+            let pending: u128 = Self::accrued_reward(principal, reward_per_share).saturating_sub(RewardDebt::<T>::take(hotkey, &coldkey));
+            let pending: u64 = pending.min(u64::MAX as u128) as u64;
+            let minted: u64 = if pending > 0 { Self::mint_locked_balance_to_coldkey_account(&coldkey, pending) } else { 0 };
+            if minted < pending { Self::decrease_total_issuance(pending - minted); }
+            Self::queue_unbonding_from_hotkey(&coldkey, hotkey, principal.saturating_add(minted));
+            returned = returned.saturating_add(principal.saturating_add(pending));
+        }
+        let unaccounted: u64 = hotkey_stake.saturating_sub(returned);
+        if unaccounted > 0 {
+            let owner: T::AccountId = Self::get_coldkey_for_hotkey(hotkey);
+            let minted: u64 = Self::mint_locked_balance_to_coldkey_account(&owner, unaccounted);
+            if minted < unaccounted { Self::decrease_total_issuance(unaccounted - minted); }
+            Self::queue_unbonding_from_hotkey(&owner, hotkey, minted);
+        }
+        Self::decrease_total_stake(hotkey_stake);
+        Self::remove_global_stake(hotkey);
+        Self::remove_stake_for_subnet(hotkey);
+        DelegatedStake::<T>::remove(hotkey);
+        Delegates::<T>::remove(hotkey);
+    }
+
+    /// Queues locked balance released from the hotkey in the coldkey's unbonding queue and emits
+    /// StakeUnbonding. When the queue is full the amount is merged into the last chunk.
+    ///
+    fn queue_unbonding_from_hotkey(coldkey: &T::AccountId, hotkey: &T::AccountId, amount: u64) {
+        if amount == 0 { return }
+        let unlock_block: u64 = Self::add_or_merge_unbonding_chunk(coldkey, hotkey, amount);
+        Self::deposit_event(Event::StakeUnbonding(coldkey.clone(), hotkey.clone(), amount, unlock_block));
+    }
+
     /// This adds stake (balance) to a cold key account. It takes the account id of the coldkey account and a Balance as parameters.
     /// The Balance parameter is a from u64 converted number. This is needed for T::Currency to work.
     /// Make sure stake is removed from another account before calling this method, otherwise you'll end up with double the value
@@ -112,45 +379,67 @@ impl<T: Config> Pallet<T> {
         TotalStake::<T>::put(total_stake.saturating_sub(decrement));
     }
 
+    /// Returns true if the hotkey is owned by the coldkey. A coldkey may own many hotkeys.
+    ///
     pub fn hotkey_belongs_to_coldkey(hotkey: &T::AccountId, coldkey: &T::AccountId) -> bool {
-        return Coldkeys::<T>::get(coldkey) == *hotkey;
+        return Owner::<T>::contains_key(hotkey) && Owner::<T>::get(hotkey) == *coldkey;
     }
 
-     /// Checks if the coldkey account has enough balance to be able to withdraw the specified amount.
+    /// Checks if the coldkey account has enough balance which is not yet locked as stake to lock the specified amount.
     ///
-    pub fn can_remove_balance_from_coldkey_account(coldkey: &T::AccountId, amount: <<T as Config>::Currency as Currency<<T as system::Config>::AccountId>>::Balance) -> bool {
-        let current_balance = Self::get_coldkey_balance(coldkey);
-        if amount > current_balance {
-            return false;
-        }
+    pub fn can_lock_balance_on_coldkey_account(coldkey: &T::AccountId, amount: u64) -> bool {
+        return match Self::u64_to_balance(Self::get_locked_balance(coldkey).saturating_add(amount)) {
+            Some(locked) => Self::get_coldkey_balance(coldkey) >= locked,
+            None => false
+        };
+    }
 
-        // This bit is currently untested. @todo
-        let new_potential_balance = current_balance - amount;
-        let can_withdraw = T::Currency::ensure_can_withdraw(&coldkey, amount, WithdrawReasons::except(WithdrawReasons::TIP), new_potential_balance).is_ok();
-        can_withdraw
+    /// Returns the balance of the cold key account held under the stake lock.
+    ///
+    pub fn get_locked_balance(coldkey: &T::AccountId) -> u64 {
+        return LockedStake::<T>::get(coldkey);
     }
 
-    /// Returns the current balance in the cold key account
+    /// Adds the amount to the balance of the coldkey account held under the stake lock.
+    /// The caller must check the coldkey holds enough unlocked balance.
     ///
-    pub fn get_coldkey_balance(coldkey: &T::AccountId) -> <<T as Config>::Currency as Currency<<T as system::Config>::AccountId>>::Balance {
-        return T::Currency::free_balance(&coldkey);
+    pub fn lock_balance_on_coldkey_account(coldkey: &T::AccountId, amount: u64) {
+        Self::set_stake_lock(coldkey, Self::get_locked_balance(coldkey).saturating_add(amount));
     }
 
-        /// This removes stake from the hotkey. This should be used together with the function to store the stake
-    /// in the hot key account.
-    /// The internal mechanics can fail. When this happens, this function returns false, otherwise true
-    /// The output of this function MUST be checked before writing the amount to the hotkey account
+    /// Releases the amount from the balance of the coldkey account held under the stake lock.
     ///
+    pub fn unlock_balance_on_coldkey_account(coldkey: &T::AccountId, amount: u64) {
+        Self::set_stake_lock(coldkey, Self::get_locked_balance(coldkey).saturating_sub(amount));
+    }
+
+    /// Mints the amount into the coldkey account and holds what was credited under the stake lock.
+    /// Returns the amount locked, which is zero when the coldkey account does not exist and the
+    /// amount is below the existential deposit.
     ///
-    pub fn remove_balance_from_coldkey_account(coldkey: &T::AccountId, amount: <<T as Config>::Currency as Currency<<T as system::Config>::AccountId>>::Balance) -> bool {
-        return match T::Currency::withdraw(&coldkey, amount, WithdrawReasons::except(WithdrawReasons::TIP), ExistenceRequirement::KeepAlive) {
-            Ok(_result) => {
-                true
-            }
-            Err(_error) => {
-                false
-            }
-        };
+    pub fn mint_locked_balance_to_coldkey_account(coldkey: &T::AccountId, amount: u64) -> u64 {
+        let credit = match Self::u64_to_balance(amount) { Some(credit) => credit, None => return 0 };
+        let minted: u64 = TryInto::<u64>::try_into(T::Currency::deposit_creating(coldkey, credit).peek()).ok().unwrap_or(0);
+        Self::lock_balance_on_coldkey_account(coldkey, minted);
+        minted
+    }
+
+    /// Sets the stake lock of the coldkey account to the amount, removing the lock when nothing is locked.
+    ///
+    fn set_stake_lock(coldkey: &T::AccountId, locked: u64) {
+        if locked == 0 {
+            LockedStake::<T>::remove(coldkey);
+            T::Currency::remove_lock(STAKE_LOCK_ID, coldkey);
+        } else {
+            LockedStake::<T>::insert(coldkey, locked);
+            T::Currency::set_lock(STAKE_LOCK_ID, coldkey, Self::u64_to_balance(locked).unwrap(), WithdrawReasons::all());
+        }
+    }
+
+    /// Returns the current balance in the cold key account
+    ///
+    pub fn get_coldkey_balance(coldkey: &T::AccountId) -> <<T as Config>::Currency as Currency<<T as system::Config>::AccountId>>::Balance {
+        return T::Currency::free_balance(&coldkey);
     }
 
     /// Increases the amount of stake in the hotkey account by the amount provided
@@ -178,11 +467,11 @@ impl<T: Config> Pallet<T> {
         Self::increase_total_stake(amount);
 
     }
-     /// Checks if the hotkey account of the specified account has enough stake to be able to withdraw
+     /// Checks if the coldkey holds enough stake on the hotkey to be able to withdraw
     /// the requested amount.
     ///
-    pub fn has_enough_stake(hotkey: &T::AccountId, amount: u64) -> bool {
-        let stake = Stake::<T>::get(hotkey);
+    pub fn has_enough_stake(coldkey: &T::AccountId, hotkey: &T::AccountId, amount: u64) -> bool {
+        let stake = Self::get_stake_for_coldkey_and_hotkey(coldkey, hotkey);
         return stake >= amount;
     }
       /// Decreases the amount of stake in a hotkey account by the amount provided
@@ -204,7 +493,7 @@ impl<T: Config> Pallet<T> {
         Stake::<T>::insert(&hotkey, decreased_stake);
         Self::decrease_total_stake(amount);
         //
-        Self::remove_stake_for_subnet(hotkey);
+        Self::sub_stake_for_subnet(hotkey, amount);
     }
      /// Increases the amount of stake of the entire stake pool by the supplied amount
     ///
@@ -236,4 +525,14 @@ impl<T: Config> Pallet<T> {
             }
         }
     }
-}
\ No newline at end of file
+    pub fn sub_stake_for_subnet( hotkey: &T::AccountId, amount: u64){
+
+        if Subnets::<T>::contains_key(&hotkey){
+            for netuid in Subnets::<T>::get(&hotkey){
+                let neuron_uid = Self::get_neuron_for_net_and_hotkey(netuid, hotkey);
+                let prev_stake = S::<T>::get(netuid, neuron_uid);
+                S::<T>::insert(netuid, neuron_uid, prev_stake.saturating_sub(amount));
+            }
+        }
+    }
+}
@@ -0,0 +1,99 @@
+use super::*;
+use frame_support::inherent::Vec;
+use frame_support::RuntimeDebug;
+use frame_support::traits::Get;
+use codec::{Decode, Encode};
+use scale_info::TypeInfo;
+
+/// ---- Stake removed from a hotkey which is released to the coldkey once unlock_block is reached.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct UnbondingChunk<AccountId> {
+    pub hotkey: AccountId,
+    pub amount: u64,
+    pub unlock_block: u64,
+}
+
+impl<T: Config> Pallet<T> {
+
+    /// ---- The implementation for the extrinsic withdraw_unbonded. Every chunk in the caller's
+    /// unbonding queue which has reached its unlock block is released from the stake lock.
+    ///
+    /// # Args:
+    /// 	* 'origin': (<T as frame_system::Config>Origin):
+    /// 		- The signature of the coldkey which removed the stake.
+    ///
+    /// # Event:
+    /// 	* UnbondedWithdrawn;
+    /// 		- On successfully releasing the unlocked chunks.
+    ///
+    /// # Raises:
+    /// 	* 'NothingToWithdraw':
+    /// 		- No chunk in the caller's queue has reached its unlock block. Unlocked chunks
+    /// 		are released even when they sum to zero.
+    ///
+    pub fn do_withdraw_unbonded( origin: T::Origin ) -> dispatch::DispatchResult {
+
+        // --- 1. Check the caller's signature. This is the coldkey which removed the stake.
+        let coldkey = ensure_signed( origin )?;
+
+        // --- 2. Split the queue into the unlocked chunks and the chunks still unbonding.
+        let current_block: u64 = Self::get_current_block_as_u64();
+        let ( unlocked, unbonding ): ( Vec<UnbondingChunk<T::AccountId>>, Vec<UnbondingChunk<T::AccountId>> ) = Unbonding::<T>::get( &coldkey )
+            .into_iter()
+            .partition( |chunk| chunk.unlock_block <= current_block );
+        ensure!( !unlocked.is_empty(), Error::<T>::NothingToWithdraw );
+        let amount: u64 = unlocked.iter().fold( 0u64, |sum, chunk| sum.saturating_add( chunk.amount ) );
+
+        // --- 3. Keep the chunks still unbonding and release the rest.
+        if unbonding.is_empty() { Unbonding::<T>::remove( &coldkey ); } else { Unbonding::<T>::insert( &coldkey, unbonding ); }
+        Self::unlock_balance_on_coldkey_account( &coldkey, amount );
+
+        // --- 4. Emit the tracking event.
+        Self::deposit_event( Event::UnbondedWithdrawn( coldkey, amount ) );
+
+        // --- 5. Return ok.
+        Ok(())
+    }
+
+    /// ==========================
+	/// ==== Helper functions ====
+	/// ==========================
+
+    // --- Returns true if another chunk fits in the coldkey's unbonding queue.
+    pub fn can_add_unbonding_chunk( coldkey: &T::AccountId ) -> bool {
+        return ( Unbonding::<T>::decode_len( coldkey ).unwrap_or( 0 ) as u32 ) < T::MaxUnbondingChunks::get();
+    }
+
+    // --- Queues stake removed from the hotkey for release to the coldkey after the unbonding period.
+    // Returns the block at which the chunk unlocks.
+    pub fn add_unbonding_chunk( coldkey: &T::AccountId, hotkey: &T::AccountId, amount: u64 ) -> u64 {
+        let unlock_block: u64 = Self::get_current_block_as_u64().saturating_add( Self::get_unbonding_period() );
+        Unbonding::<T>::mutate( coldkey, |chunks| chunks.push( UnbondingChunk { hotkey: hotkey.clone(), amount: amount, unlock_block: unlock_block } ) );
+        unlock_block
+    }
+
+    // --- Queues the amount like add_unbonding_chunk. When the queue is full the amount is merged
+    // into the last chunk, which then unlocks with the new amount. Returns the block at which it unlocks.
+    pub fn add_or_merge_unbonding_chunk( coldkey: &T::AccountId, hotkey: &T::AccountId, amount: u64 ) -> u64 {
+        if Self::can_add_unbonding_chunk( coldkey ) { return Self::add_unbonding_chunk( coldkey, hotkey, amount ) }
+        let unlock_block: u64 = Self::get_current_block_as_u64().saturating_add( Self::get_unbonding_period() );
+        Unbonding::<T>::mutate( coldkey, |chunks| match chunks.last_mut() {
+            Some( last ) => {
+                last.amount = last.amount.saturating_add( amount );
+                last.unlock_block = last.unlock_block.max( unlock_block );
+            },
+            None => chunks.push( UnbondingChunk { hotkey: hotkey.clone(), amount: amount, unlock_block: unlock_block } ),
+        });
+        unlock_block
+    }
+
+    // --- Returns the chunks in the coldkey's unbonding queue.
+    pub fn get_unbonding_chunks( coldkey: &T::AccountId ) -> Vec<UnbondingChunk<T::AccountId>> {
+        return Unbonding::<T>::get( coldkey );
+    }
+
+    // --- Returns the number of blocks removed stake stays locked for.
+    pub fn get_unbonding_period() -> u64 {
+        return UnbondingPeriod::<T>::get();
+    }
+}
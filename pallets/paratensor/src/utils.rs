@@ -11,18 +11,20 @@ impl<T: Config> Pallet<T> {
 	/// ==== Misc ====
 	/// ==============
     pub fn get_total_issuance() -> u64 { return TotalIssuance::<T>::get() }
+    pub fn increase_total_issuance( increment: u64 ) { TotalIssuance::<T>::mutate( |issuance| *issuance = issuance.saturating_add( increment ) ) }
+    pub fn decrease_total_issuance( decrement: u64 ) { TotalIssuance::<T>::mutate( |issuance| *issuance = issuance.saturating_sub( decrement ) ) }
     pub fn get_current_block_as_u64( ) -> u64 {
         let block_as_u64: u64 = TryInto::try_into( system::Pallet::<T>::block_number() ).ok().expect("blockchain will not exceed 2^64 blocks; QED.");
         block_as_u64
     }
-    pub fn get_registrations_this_block( ) -> u16 {
-        RegistrationsThisBlock::<T>::get()
+    pub fn get_registrations_this_block( netuid: u16 ) -> u16 {
+        RegistrationsThisBlock::<T>::get( netuid )
     }
-    pub fn get_max_registratations_per_block( ) -> u16 {
-        MaxRegistrationsPerBlock::<T>::get()
+    pub fn get_max_registratations_per_block( netuid: u16 ) -> u16 {
+        MaxRegistrationsPerBlock::<T>::get( netuid )
     }
-    pub fn set_max_registratations_per_block( max_registrations: u16 ){
-        MaxRegistrationsPerBlock::<T>::put( max_registrations );
+    pub fn set_max_registratations_per_block( netuid: u16, max_registrations: u16 ){
+        MaxRegistrationsPerBlock::<T>::insert( netuid, max_registrations );
     }
 
     pub fn get_difficulty(netuid: u16 ) -> U256 {
@@ -37,11 +39,30 @@ impl<T: Config> Pallet<T> {
         return MaxAllowedUids::<T>::get(netuid);
     }
 
-    // --- Returns the next available network uid and increments uid.
-		pub fn get_next_uid() -> u16 {
-			let uid = GlobalN::<T>::get();
+    pub fn set_max_allowed_uids(netuid: u16, max_allowed_uids: u16 ) {
+        MaxAllowedUids::<T>::insert(netuid, max_allowed_uids);
+    }
+
+    pub fn get_min_allowed_weights(netuid: u16 ) -> u16 {
+        return MinAllowedWeights::<T>::get(netuid);
+    }
+
+    pub fn set_min_allowed_weights(netuid: u16, min_allowed_weights: u16 ) {
+        MinAllowedWeights::<T>::insert( netuid, min_allowed_weights );
+    }
+
+    pub fn get_max_allowed_max_min_ratio(netuid: u16 ) -> u16 {
+        return MaxAllowedMaxMinRatio::<T>::get(netuid);
+    }
+
+    pub fn set_max_allowed_max_min_ratio(netuid: u16, max_allowed_max_min_ratio: u16 ) {
+        MaxAllowedMaxMinRatio::<T>::insert( netuid, max_allowed_max_min_ratio );
+    }
+
+    // --- Returns the next available uid on the network. Uids on each network are dense in 0..SubnetworkN.
+		pub fn get_next_uid( netuid: u16 ) -> u16 {
+			let uid = SubnetworkN::<T>::get( netuid );
 			assert!(uid < u16::MAX);  // The system should fail if this is ever reached.
-			GlobalN::<T>::put(uid + 1);
 			uid
 		}
 
@@ -70,6 +91,15 @@ impl<T: Config> Pallet<T> {
         pub fn get_stake_pruning_min(netuid: u16) -> u16 {
 			return StakePruningMin::<T>::get(netuid);
 		}
+        pub fn set_stake_pruning_min(netuid: u16, stake_pruning_min: u16) {
+			StakePruningMin::<T>::insert(netuid, stake_pruning_min);
+		}
+        pub fn set_stake_pruning_denominator(netuid: u16, stake_pruning_denominator: u16) {
+			StakePruningDenominator::<T>::insert(netuid, stake_pruning_denominator);
+		}
+        pub fn set_incentive_pruning_denominator(netuid: u16, incentive_pruning_denominator: u16) {
+			IncentivePruningDenominator::<T>::insert(netuid, incentive_pruning_denominator);
+		}
 		pub fn get_registrations_this_interval( netuid: u16) -> u16 {
 			return RegistrationsThisInterval::<T>::get(netuid);
 		}
@@ -90,26 +120,44 @@ impl<T: Config> Pallet<T> {
             }
         }
   
-        pub fn get_neuron_to_prune(netuid: u16) -> u16 {
-            let mut min_score : u16 = u16::MAX;
-            let mut uid_with_min_score = 0;
-            for (uid_i, _prune_score) in <PrunningScores<T> as IterableStorageDoubleMap<u16, u16, u16 >>::iter_prefix( netuid ) {
-                let value = PrunningScores::<T>::get(netuid, uid_i);
-                if min_score > value { 
-                    min_score = value; 
-                    uid_with_min_score = uid_i;
+        // --- Returns the uid with the lowest pruning score among the neurons which are past their
+        // immunity period. Ties are broken by the oldest registration and then the lowest uid.
+        // Returns None if every neuron on the network is still immune.
+        pub fn get_neuron_to_prune(netuid: u16) -> Option<u16> {
+            let current_block: u64 = Self::get_current_block_as_u64();
+            let immunity_period: u64 = Self::get_immunity_period(netuid) as u64;
+            let mut uid_to_prune: Option<(u16, u64, u16)> = None; // (score, block at registration, uid)
+            for (uid_i, _hotkey) in <Keys<T> as IterableStorageDoubleMap<u16, u16, T::AccountId>>::iter_prefix( netuid ) {
+                let block_at_registration: u64 = Self::get_neuron_block_at_registration(netuid, uid_i);
+                if current_block.saturating_sub(block_at_registration) < immunity_period { continue } // Still immune.
+                let candidate = (PrunningScores::<T>::get(netuid, uid_i), block_at_registration, uid_i);
+                match uid_to_prune {
+                    Some(current) if current <= candidate => {},
+                    _ => uid_to_prune = Some(candidate),
                 }
             }
-            uid_with_min_score
+            uid_to_prune.map(|(_, _, uid)| uid)
         } 
+        pub fn get_neuron_block_at_registration(netuid: u16, neuron_uid: u16) -> u64 {
+            BlockAtRegistration::<T>::get(netuid, neuron_uid)
+        }
+        pub fn get_last_update_for_uid(netuid: u16, neuron_uid: u16) -> u64 {
+            LastUpdate::<T>::get(netuid, neuron_uid)
+        }
+        pub fn get_active(netuid: u16) -> Vec<bool> {
+            Active::<T>::get(netuid)
+        }
+        pub fn get_validator_permit(netuid: u16) -> Vec<bool> {
+            ValidatorPermit::<T>::get(netuid)
+        }
         pub fn get_neuron_stake_for_subnetwork(netuid: u16, neuron_uid: u16) -> u64 {
             S::<T>::get(netuid, neuron_uid)
         }
-        pub fn get_target_registrations_per_interval() -> u16 {
-			TargetRegistrationsPerInterval::<T>::get()
+        pub fn get_target_registrations_per_interval( netuid: u16 ) -> u16 {
+			TargetRegistrationsPerInterval::<T>::get( netuid )
 		}
-        pub fn get_adjustment_interval() -> u16 {
-			AdjustmentInterval::<T>::get()
+        pub fn get_adjustment_interval( netuid: u16 ) -> u16 {
+			AdjustmentInterval::<T>::get( netuid )
 		}
         pub fn get_blocks_since_last_step( ) -> u64 {
 			BlocksSinceLastStep::<T>::get()
@@ -127,33 +175,81 @@ impl<T: Config> Pallet<T> {
         pub fn get_last_mechanism_step_block( ) -> u64 {
 			return LastMechansimStepBlock::<T>::get();
 		}
+        pub fn get_pending_emission( netuid: u16 ) -> u64 {
+			return PendingEmission::<T>::get( netuid );
+		}
+        pub fn get_tempo( netuid: u16 ) -> u16 {
+			return Tempo::<T>::get( netuid );
+		}
+        pub fn set_tempo( netuid: u16, tempo: u16 ) {
+			Tempo::<T>::insert( netuid, tempo );
+		}
+        pub fn set_emission_ratio( netuid: u16, emission_ratio: u16 ) {
+			EmissionRatio::<T>::insert( netuid, emission_ratio );
+		}
         pub fn set_difficulty_from_u64( netuid: u16, difficulty: u64 ) {
 			Difficulty::<T>::insert( netuid, difficulty );
 		}
         pub fn set_prunning_score(netuid:u16, neuron_uid: u16, prunning_score: u16){
             PrunningScores::<T>::insert(netuid, neuron_uid, prunning_score);
         }
+        pub fn get_prunning_score(netuid:u16, neuron_uid: u16) -> u16 {
+            PrunningScores::<T>::get(netuid, neuron_uid)
+        }
+        pub fn get_rho( netuid: u16 ) -> u16 {
+			return Rho::<T>::get( netuid );
+		}
+        pub fn get_kappa( netuid: u16 ) -> u16 {
+			return Kappa::<T>::get( netuid );
+		}
+        pub fn get_bonds_moving_average( netuid: u16 ) -> u64 {
+			return BondsMovingAverage::<T>::get( netuid );
+		}
+        pub fn get_trust_threshold( netuid: u16 ) -> u16 {
+			return TrustThreshold::<T>::get( netuid );
+		}
+        pub fn get_max_allowed_validators( netuid: u16 ) -> u16 {
+			return MaxAllowedValidators::<T>::get( netuid );
+		}
+        pub fn get_activity_cutoff( netuid: u16 ) -> u16 {
+			return ActivityCutoff::<T>::get( netuid );
+		}
+        pub fn get_validator_batch_size( netuid: u16 ) -> u16 {
+			return ValidatorBatchSize::<T>::get( netuid );
+		}
+        pub fn get_validator_sequence_length( netuid: u16 ) -> u16 {
+			return ValidatorSequenceLength::<T>::get( netuid );
+		}
+        pub fn get_validator_epoch_length( netuid: u16 ) -> u16 {
+			return ValidatorEpochLen::<T>::get( netuid );
+		}
+        pub fn get_validator_epochs_per_reset( netuid: u16 ) -> u16 {
+			return ValidatorEpochsPerReset::<T>::get( netuid );
+		}
        
 
     /// =========================
 	/// ==== Global Accounts ====
 	/// =========================
     pub fn get_global_n() -> u16 { return GlobalN::<T>::get() }
-    pub fn is_hotkey_globally_active( hotkey: &T::AccountId ) -> bool { return Coldkeys::<T>::contains_key( hotkey ) }
+    pub fn is_hotkey_globally_active( hotkey: &T::AccountId ) -> bool { return Owner::<T>::contains_key( hotkey ) }
     pub fn increment_global_n() { let n = GlobalN::<T>::get(); if n < u16::MAX { GlobalN::<T>::put(n + 1); } }
     pub fn decrement_global_n() { let n = GlobalN::<T>::get(); if n > 0 { GlobalN::<T>::put(n - 1); } }
     pub fn add_global_account( hotkey: &T::AccountId, coldkey: &T::AccountId )  {
-        if !Hotkeys::<T>::contains_key( &hotkey ) { 
-            Hotkeys::<T>::insert( hotkey.clone(), coldkey.clone() );
-            Coldkeys::<T>::insert( coldkey.clone(), hotkey.clone() );
+        if !Owner::<T>::contains_key( &hotkey ) { 
+            Owner::<T>::insert( hotkey.clone(), coldkey.clone() );
+            OwnedHotkeys::<T>::mutate( coldkey, |hotkeys| hotkeys.push( hotkey.clone() ) );
             //Self::increment_global_n();
         }
     }
     pub fn remove_global_account( hotkey: &T::AccountId )  {
-        if Hotkeys::<T>::contains_key( &hotkey ) { 
-            let coldkey = Coldkeys::<T>::get( &hotkey.clone() );
-            Hotkeys::<T>::remove( coldkey.clone() );
-            Coldkeys::<T>::remove( hotkey.clone() );
+        if Owner::<T>::contains_key( &hotkey ) { 
+            let coldkey = Owner::<T>::take( &hotkey );
+            let mut hotkeys: Vec<T::AccountId> = OwnedHotkeys::<T>::get( &coldkey );
+            hotkeys.retain( |owned| owned != hotkey );
+            if hotkeys.is_empty() { OwnedHotkeys::<T>::remove( &coldkey ); } else { OwnedHotkeys::<T>::insert( &coldkey, hotkeys ); }
+            LastServedAxon::<T>::remove( hotkey );
+            LastServedPrometheus::<T>::remove( hotkey );
             Self::decrement_global_n();
         }
     }
@@ -178,6 +274,12 @@ impl<T: Config> Pallet<T> {
         Uids::<T>::insert( netuid, hotkey.clone(), uid );
         Self::increment_subnetwork_n( netuid );
     }
+    pub fn replace_subnetwork_account( netuid:u16, uid: u16, hotkey: &T::AccountId ) { 
+        let old_hotkey = Keys::<T>::get( netuid, uid );
+        Uids::<T>::remove( netuid, old_hotkey );
+        Keys::<T>::insert( netuid, uid, hotkey.clone() ); 
+        Uids::<T>::insert( netuid, hotkey.clone(), uid );
+    }
     pub fn remove_subnetwork_account( netuid:u16, uid: u16 ) { 
         let hotkey = Keys::<T>::get( netuid, uid );
         Uids::<T>::remove( netuid, hotkey.clone() );
@@ -185,11 +287,11 @@ impl<T: Config> Pallet<T> {
         Self::decrement_subnetwork_n( netuid );
     }
     pub fn get_coldkey_for_hotkey(hotkey:  &T::AccountId) ->  T::AccountId {
-        return Hotkeys::<T>::get(hotkey);
+        return Owner::<T>::get(hotkey);
     }
 
-    pub fn get_hotkey_for_coldkey(coldkey: &T::AccountId) -> T::AccountId {
-        return Coldkeys::<T>::get(coldkey);
+    pub fn get_owned_hotkeys(coldkey: &T::AccountId) -> Vec<T::AccountId> {
+        return OwnedHotkeys::<T>::get(coldkey);
     }
 
     pub fn get_subnets_for_hotkey(hotkey: T::AccountId) -> Vec<u16> {
@@ -218,6 +320,15 @@ impl<T: Config> Pallet<T> {
             Subnets::<T>::insert(&hotkey, vec_new_hotkey_subnets); 
         }
     }
+    pub fn decrement_subnets_for_hotkey(netuid: u16, hotkey: &T::AccountId){
+        let mut vec_hotkey_subnets: Vec<u16> = Subnets::<T>::get(&hotkey);
+        vec_hotkey_subnets.retain(|subnet| *subnet != netuid);
+        if vec_hotkey_subnets.is_empty() {
+            Subnets::<T>::remove(&hotkey);
+        } else {
+            Subnets::<T>::insert(&hotkey, vec_hotkey_subnets);
+        }
+    }
     //check if horkey is registered on any network
     pub fn is_hotkey_active(hotkey:  &T::AccountId)-> bool {
         return Subnets::<T>::contains_key( hotkey)
@@ -282,6 +393,9 @@ impl<T: Config> Pallet<T> {
     pub fn if_bonds_is_set_for_neuron(netuid: u16, neuron_uid: u16) -> bool{
         Bonds::<T>::contains_key(netuid, neuron_uid)
     }
+    pub fn get_bonds_for_neuron(netuid: u16, neuron_uid: u16) -> Vec<(u16, u16)> {
+        Bonds::<T>::get(netuid, neuron_uid)
+    }
     //
     pub fn remove_weights_from_subnet(netuid:u16, neuron_uid: u16){
         Weights::<T>::remove(netuid, neuron_uid);
@@ -289,6 +403,9 @@ impl<T: Config> Pallet<T> {
     pub fn if_weights_is_set_for_neuron(netuid: u16, neuron_uid: u16) -> bool{
         Weights::<T>::contains_key(netuid, neuron_uid)
     }
+    pub fn get_weights_for_neuron(netuid: u16, neuron_uid: u16) -> Vec<(u16, u16)> {
+        Weights::<T>::get(netuid, neuron_uid)
+    }
 }
 
 
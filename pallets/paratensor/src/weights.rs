@@ -0,0 +1,160 @@
+use super::*;
+use frame_support::inherent::Vec;
+use frame_support::sp_std::vec;
+
+impl<T: Config> Pallet<T> {
+
+    /// ---- The implementation for the extrinsic set_weights.
+    ///
+    /// # Args:
+    /// 	* 'origin': (<T as frame_system::Config>Origin):
+    /// 		- The signature of the calling hotkey.
+    ///
+    /// 	* 'netuid' (u16):
+    /// 		- The network uid we are setting these weights on.
+    ///
+    /// 	* 'uids' (Vec<u16>):
+    /// 		- The edge endpoint for the weight, i.e. j for w_ij.
+    ///
+    /// 	* 'values' (Vec<u16>):
+    /// 		- The u16 integer encoded weights, normalized here to sum to u16::MAX.
+    ///
+    /// # Event:
+    /// 	* WeightsSet;
+    /// 		- On successfully setting the weights on chain.
+    ///
+    /// # Raises:
+    /// 	* 'NetworkDoesNotExist':
+    /// 		- The network has not been added.
+    ///
+    /// 	* 'NotRegistered':
+    /// 		- The calling hotkey is not registered on the network.
+    ///
+    /// 	* 'WeightVecNotEqualSize':
+    /// 		- The passed weights and uids have unequal size.
+    ///
+    /// 	* 'DuplicateUids':
+    /// 		- The passed uids contain duplicates.
+    ///
+    /// 	* 'InvalidUid':
+    /// 		- One of the passed uids does not exist on the network.
+    ///
+    /// 	* 'NotSettingEnoughWeights':
+    /// 		- Fewer weights than MinAllowedWeights were passed.
+    ///
+    /// 	* 'MaxAllowedMaxMinRatioExceeded':
+    /// 		- The ratio between the largest and smallest weight exceeds MaxAllowedMaxMinRatio.
+    ///
+    pub fn do_set_weights( origin: T::Origin, netuid: u16, uids: Vec<u16>, values: Vec<u16> ) -> dispatch::DispatchResult {
+
+        // --- 1. Check the caller's signature. This is the hotkey of a registered account.
+        let hotkey = ensure_signed( origin )?;
+
+        // --- 2. Check that the network exists and the hotkey is registered on it.
+        ensure!( Self::if_subnet_exist( netuid ), Error::<T>::NetworkDoesNotExist );
+        ensure!( Self::is_hotkey_subnetwork_active( netuid, &hotkey ), Error::<T>::NotRegistered );
+
+        // --- 3. Check that the length of uid list and value list are equal.
+        ensure!( Self::uids_match_values( &uids, &values ), Error::<T>::WeightVecNotEqualSize );
+
+        // --- 4. Ensure the passed uids contain no duplicates.
+        ensure!( !Self::has_duplicate_uids( &uids ), Error::<T>::DuplicateUids );
+
+        // --- 5. Ensure that the passed uids are valid for the network.
+        ensure!( !Self::contains_invalid_uids( netuid, &uids ), Error::<T>::InvalidUid );
+
+        // --- 6. Check that the number of weights is at least the min allowed.
+        let neuron_uid: u16 = Self::get_neuron_for_net_and_hotkey( netuid, &hotkey );
+        ensure!( Self::check_length( netuid, neuron_uid, &uids, &values ), Error::<T>::NotSettingEnoughWeights );
+
+        // --- 7. Normalize the weights.
+        let normalized_values: Vec<u16> = Self::normalize_weights( values );
+
+        // --- 8. Check the max min ratio of the normalized weights.
+        ensure!( Self::max_min_ratio_within_limit( netuid, &normalized_values ), Error::<T>::MaxAllowedMaxMinRatioExceeded );
+
+        // --- 9. Zip weights for sinking to the storage map.
+        let mut zipped_weights: Vec<(u16, u16)> = vec![];
+        for ( uid, val ) in uids.iter().zip( normalized_values.iter() ) { zipped_weights.push( ( *uid, *val ) ) }
+
+        // --- 10. Set weights under the netuid, uid double map entry.
+        Weights::<T>::insert( netuid, neuron_uid, zipped_weights );
+
+        // --- 11. Record the block of this update, used to mark the neuron as active.
+        LastUpdate::<T>::insert( netuid, neuron_uid, Self::get_current_block_as_u64() );
+
+        // --- 12. Emit the tracking event.
+        Self::deposit_event( Event::WeightsSet( netuid, neuron_uid ) );
+
+        // --- 13. Return ok.
+        Ok(())
+    }
+
+    /// ==========================
+	/// ==== Helper functions ====
+	/// ==========================
+
+    // --- Returns true if the uids and values are of the same length.
+    pub fn uids_match_values( uids: &Vec<u16>, values: &Vec<u16> ) -> bool {
+        return uids.len() == values.len();
+    }
+
+    // --- Returns true if the passed uids contain a duplicate.
+    pub fn has_duplicate_uids( items: &Vec<u16> ) -> bool {
+        let mut parsed: Vec<u16> = Vec::new();
+        for item in items {
+            if parsed.contains( item ) { return true; }
+            parsed.push( *item );
+        }
+        return false;
+    }
+
+    // --- Returns true if any of the passed uids are not in the network.
+    pub fn contains_invalid_uids( netuid: u16, uids: &Vec<u16> ) -> bool {
+        for uid in uids {
+            if !Self::is_subnetwork_uid_active( netuid, *uid ) { return true; }
+        }
+        return false;
+    }
+
+    // --- Returns true if the caller is setting enough weights. A single self weight is always allowed.
+    pub fn check_length( netuid: u16, uid: u16, uids: &Vec<u16>, weights: &Vec<u16> ) -> bool {
+        let min_allowed_length: usize = Self::get_min_allowed_weights( netuid ) as usize;
+
+        // Check self weight. Allowed to set a single value for self weight.
+        if Self::is_self_weight( uid, uids, weights ) { return true; }
+
+        // Check if number of weights exceeds min.
+        if weights.len() >= min_allowed_length { return true; }
+
+        // To few weights.
+        return false;
+    }
+
+    // --- Returns true if the uids contain only the caller's own uid.
+    pub fn is_self_weight( uid: u16, uids: &Vec<u16>, weights: &Vec<u16> ) -> bool {
+        if weights.len() != 1 { return false; }
+        if uid != uids[0] { return false; }
+        return true;
+    }
+
+    // --- Normalizes the passed weights so that they sum to u16::MAX.
+    pub fn normalize_weights( mut weights: Vec<u16> ) -> Vec<u16> {
+        let sum: u64 = weights.iter().map( |x| *x as u64 ).sum();
+        if sum == 0 { return weights; }
+        weights.iter_mut().for_each( |x| { *x = ( *x as u64 * u16::MAX as u64 / sum ) as u16; } );
+        return weights;
+    }
+
+    // --- Returns true if the ratio between the max and min weight is within MaxAllowedMaxMinRatio.
+    // A ratio of zero disables the check.
+    pub fn max_min_ratio_within_limit( netuid: u16, weights: &Vec<u16> ) -> bool {
+        let max_allowed_max_min_ratio: u16 = Self::get_max_allowed_max_min_ratio( netuid );
+        if max_allowed_max_min_ratio == 0 { return true; }
+        if weights.len() <= 1 { return true; }
+        let max: u64 = *weights.iter().max().unwrap() as u64;
+        let min: u64 = *weights.iter().min().unwrap() as u64;
+        if min == 0 { return false; }
+        return max <= min * max_allowed_max_min_ratio as u64;
+    }
+}
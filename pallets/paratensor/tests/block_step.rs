@@ -0,0 +1,121 @@
+use pallet_paratensor::{Error};
+use frame_support::{assert_ok};
+use frame_system::Config;
+use crate::{mock::*};
+use frame_support::sp_runtime::DispatchError;
+use frame_support::traits::{GetStorageVersion, StorageVersion};
+
+mod mock;
+
+/********************************************
+	block_step::mechanism_step() tests
+*********************************************/
+#[test]
+fn test_emission_for_subnet() {
+	new_test_ext().execute_with(|| {
+		let netuid: u16 = 1;
+		add_network( netuid, 0, 0 );
+		assert_eq!( ParatensorModule::get_emission_for_subnet( netuid, 1_000_000_000 ), 0 );
+		assert_ok!( ParatensorModule::sudo_set_emission_ratio( <<Test as Config>::Origin>::root(), netuid, u16::MAX ) );
+		assert_eq!( ParatensorModule::get_emission_for_subnet( netuid, 1_000_000_000 ), 1_000_000_000 );
+		assert_ok!( ParatensorModule::sudo_set_emission_ratio( <<Test as Config>::Origin>::root(), netuid, u16::MAX / 2 ) );
+		assert_eq!( ParatensorModule::get_emission_for_subnet( netuid, 1_000_000_000 ), 499_992_370 );
+	});
+}
+
+#[test]
+fn test_emission_ratio_sum_cannot_exceed_max() {
+	new_test_ext().execute_with(|| {
+		add_network( 1, 0, 0 );
+		add_network( 2, 0, 0 );
+		add_network( 3, 0, 0 );
+		assert_ok!( ParatensorModule::sudo_set_emission_ratio( <<Test as Config>::Origin>::root(), 1, u16::MAX / 2 ) );
+		assert_ok!( ParatensorModule::sudo_set_emission_ratio( <<Test as Config>::Origin>::root(), 2, u16::MAX / 2 ) );
+		assert_eq!( ParatensorModule::sudo_set_emission_ratio( <<Test as Config>::Origin>::root(), 3, 10 ), Err(Error::<Test>::InvalidEmissionRatio.into()) );
+		// Overwriting an existing ratio only counts the new value.
+		assert_ok!( ParatensorModule::sudo_set_emission_ratio( <<Test as Config>::Origin>::root(), 2, u16::MAX / 2 + 1 ) );
+		assert_eq!( ParatensorModule::calculate_emission_ratio_sum(), u16::MAX as u64 );
+	});
+}
+
+#[test]
+fn test_set_emission_ratio_not_root() {
+	new_test_ext().execute_with(|| {
+		let result = ParatensorModule::sudo_set_emission_ratio( <<Test as Config>::Origin>::signed(0), 1, 10 );
+		assert_eq!( result, Err(DispatchError::BadOrigin.into()) );
+	});
+}
+
+#[test]
+fn test_mechanism_step_runs_registered_subnets() {
+	new_test_ext().execute_with(|| {
+		let netuid: u16 = 1;
+		add_network( netuid, 0, 0 );
+		register_ok_neuron( netuid, 1, 667, 0 );
+		assert_ok!( ParatensorModule::sudo_set_emission_ratio( <<Test as Config>::Origin>::root(), netuid, u16::MAX ) );
+		step_block( 1 );
+		assert_eq!( ParatensorModule::get_last_mechanism_step_block(), 1 );
+		// Tempo is zero so the epoch runs every step and nothing is left pending.
+		assert_eq!( ParatensorModule::get_pending_emission( netuid ), 0 );
+	});
+}
+
+#[test]
+fn test_mechanism_step_accumulates_until_tempo() {
+	new_test_ext().execute_with(|| {
+		let netuid: u16 = 1;
+		add_network( netuid, 0, 0 );
+		register_ok_neuron( netuid, 1, 667, 0 );
+		assert_ok!( ParatensorModule::sudo_set_emission_ratio( <<Test as Config>::Origin>::root(), netuid, u16::MAX ) );
+		ParatensorModule::set_tempo( netuid, 3 );
+		step_block( 2 );
+		assert_eq!( ParatensorModule::get_pending_emission( netuid ), 2 * ParatensorModule::get_block_emission() );
+		step_block( 1 );
+		assert_eq!( ParatensorModule::get_pending_emission( netuid ), 0 );
+	});
+}
+
+#[test]
+fn test_mechanism_step_skips_subnets_not_added() {
+	new_test_ext().execute_with(|| {
+		// A network which has neurons but is not in the NetworksAdded registry.
+		let netuid: u16 = 1;
+		ParatensorModule::add_subnetwork_account( netuid, 0, &10 );
+		ParatensorModule::set_emission_ratio( netuid, u16::MAX );
+		ParatensorModule::set_tempo( netuid, 3 );
+		step_block( 1 );
+		assert_eq!( ParatensorModule::get_pending_emission( netuid ), 0 );
+	});
+}
+
+#[test]
+fn test_migrate_to_emission_ratios() {
+	new_test_ext().execute_with(|| {
+		add_network( 1, 0, 0 );
+		add_network( 2, 0, 0 );
+		register_ok_neuron( 1, 1, 667, 0 );
+		register_ok_neuron( 2, 2, 667, 300000 );
+		StorageVersion::new( 9 ).put::<ParatensorModule>();
+
+		ParatensorModule::migrate_to_v10_emission_ratios();
+		assert_eq!( ParatensorModule::get_emission_for_subnet( 1, 1_000_000_000 ), 499_992_370 );
+		assert_eq!( ParatensorModule::get_emission_for_subnet( 2, 1_000_000_000 ), 499_992_370 );
+		assert_eq!( ParatensorModule::on_chain_storage_version(), 10 );
+	});
+}
+
+#[test]
+fn test_migrate_to_emission_ratios_keeps_set_ratios() {
+	new_test_ext().execute_with(|| {
+		add_network( 1, 0, 0 );
+		add_network( 2, 0, 0 );
+		register_ok_neuron( 1, 1, 667, 0 );
+		register_ok_neuron( 2, 2, 667, 300000 );
+		assert_ok!( ParatensorModule::sudo_set_emission_ratio( <<Test as Config>::Origin>::root(), 1, u16::MAX ) );
+		StorageVersion::new( 9 ).put::<ParatensorModule>();
+
+		ParatensorModule::migrate_to_v10_emission_ratios();
+		assert_eq!( ParatensorModule::get_emission_for_subnet( 1, 1_000_000_000 ), 1_000_000_000 );
+		assert_eq!( ParatensorModule::get_emission_for_subnet( 2, 1_000_000_000 ), 0 );
+	});
+}
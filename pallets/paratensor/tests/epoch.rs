@@ -1,4 +1,9 @@
 use crate::{mock::*};
+use frame_support::{assert_ok};
+use frame_support::sp_runtime::DispatchError;
+use pallet_paratensor::{Error};
+use frame_support::traits::{GetStorageVersion, StorageVersion};
+use substrate_fixed::types::I32F32;
 use rand::Rng;
 use std::time::{Duration, Instant};
 
@@ -83,3 +88,295 @@ fn test_many_epochs() {
 	});  */
 }
 
+
+#[test]
+fn test_epoch_empty_network() {
+	new_test_ext().execute_with(|| {
+		ParatensorModule::epoch( 1, 1_000_000_000, false );
+		assert_eq!( ParatensorModule::if_rank_is_set_for_neuron( 1, 0 ), false );
+		assert_eq!( ParatensorModule::if_emission_is_set_for_neuron( 1, 0 ), false );
+	});
+}
+
+#[test]
+fn test_epoch_persists_outputs() {
+	new_test_ext().execute_with(|| {
+		let netuid: u16 = 1;
+		add_network( netuid, 0, 0 );
+		register_ok_neuron( netuid, 1, 667, 0 );
+		register_ok_neuron( netuid, 2, 668, 300000 );
+		ParatensorModule::add_stake_to_neuron_hotkey_account( &1, 1000 );
+		assert_ok!( ParatensorModule::set_weights( Origin::signed(1), netuid, vec![1], vec![1] ) );
+		ParatensorModule::epoch( netuid, 1_000_000_000, false );
+
+		// Uid 1 receives all the weight from the only staked neuron.
+		assert_eq!( ParatensorModule::get_ranks( netuid, 0 ), 0 );
+		assert_eq!( ParatensorModule::get_ranks( netuid, 1 ), u16::MAX );
+		assert_eq!( ParatensorModule::get_trust( netuid, 1 ), u16::MAX );
+		assert_eq!( ParatensorModule::get_incentives( netuid, 1 ), u16::MAX );
+		assert!( ParatensorModule::get_consensus( netuid, 1 ) > ParatensorModule::get_consensus( netuid, 0 ) );
+		assert_eq!( ParatensorModule::get_dividends( netuid, 0 ), 0 );
+		assert_eq!( ParatensorModule::get_emission( netuid, 0 ), 0 );
+		assert_eq!( ParatensorModule::get_emission( netuid, 1 ), 1_000_000_000 );
+
+		// Uid 0 starts accruing bonds on uid 1.
+		let bonds: Vec<(u16, u16)> = ParatensorModule::get_bonds_for_neuron( netuid, 0 );
+		assert_eq!( bonds.len(), 1 );
+		assert_eq!( bonds[0].0, 1 );
+		assert!( bonds[0].1 > 0 );
+	});
+}
+
+#[test]
+fn test_epoch_emission_credited_to_stake() {
+	new_test_ext().execute_with(|| {
+		let netuid: u16 = 1;
+		add_network( netuid, 0, 0 );
+		register_ok_neuron( netuid, 1, 667, 0 );
+		register_ok_neuron( netuid, 2, 668, 300000 );
+		ParatensorModule::add_stake_to_neuron_hotkey_account( &1, 1000 );
+		assert_ok!( ParatensorModule::set_weights( Origin::signed(1), netuid, vec![1], vec![1] ) );
+		let issuance_before: u64 = ParatensorModule::get_total_issuance();
+		ParatensorModule::epoch( netuid, 1_000_000_000, false );
+
+		// The emission is minted onto the stake of uid 1's hotkey.
+		assert_eq!( ParatensorModule::get_stake_for_hotkey( &1 ), 1000 );
+		assert_eq!( ParatensorModule::get_stake_for_hotkey( &2 ), 1_000_000_000 );
+		assert_eq!( ParatensorModule::get_neuron_stake_for_subnetwork( netuid, 1 ), 1_000_000_000 );
+		assert_eq!( ParatensorModule::get_total_stake(), 1_000_001_000 );
+		assert_eq!( ParatensorModule::get_total_issuance(), issuance_before + 1_000_000_000 );
+	});
+}
+
+#[test]
+fn test_epoch_pruning_scores() {
+	new_test_ext().execute_with(|| {
+		let netuid: u16 = 1;
+		add_network( netuid, 0, 0 );
+		register_ok_neuron( netuid, 1, 667, 0 );
+		register_ok_neuron( netuid, 2, 668, 300000 );
+		ParatensorModule::add_stake_to_neuron_hotkey_account( &1, 1000 );
+		assert_ok!( ParatensorModule::set_weights( Origin::signed(1), netuid, vec![1], vec![1] ) );
+
+		// Equal denominators: uid 0 holds all the stake, uid 1 all the incentive.
+		ParatensorModule::epoch( netuid, 0, false );
+		assert_eq!( ParatensorModule::get_prunning_score( netuid, 0 ), u16::MAX / 2 );
+		assert_eq!( ParatensorModule::get_prunning_score( netuid, 1 ), u16::MAX / 2 );
+
+		// Dropping the stake term leaves incentive only.
+		ParatensorModule::set_stake_pruning_denominator( netuid, 0 );
+		ParatensorModule::epoch( netuid, 0, false );
+		assert_eq!( ParatensorModule::get_prunning_score( netuid, 0 ), 0 );
+		assert_eq!( ParatensorModule::get_prunning_score( netuid, 1 ), u16::MAX );
+
+		// Stake below the minimum does not count.
+		ParatensorModule::set_stake_pruning_denominator( netuid, 1 );
+		ParatensorModule::set_incentive_pruning_denominator( netuid, 0 );
+		ParatensorModule::set_stake_pruning_min( netuid, 1001 );
+		ParatensorModule::epoch( netuid, 0, false );
+		assert_eq!( ParatensorModule::get_prunning_score( netuid, 0 ), 0 );
+		assert_eq!( ParatensorModule::get_prunning_score( netuid, 1 ), 0 );
+	});
+}
+
+#[test]
+fn test_epoch_trust_threshold() {
+	new_test_ext().execute_with(|| {
+		let netuid: u16 = 1;
+		add_network( netuid, 0, 0 );
+		register_ok_neuron( netuid, 1, 667, 0 );
+		register_ok_neuron( netuid, 2, 668, 300000 );
+		ParatensorModule::add_stake_to_neuron_hotkey_account( &1, 1000 );
+		assert_ok!( ParatensorModule::set_weights( Origin::signed(1), netuid, vec![0, 1], vec![1, 1] ) );
+
+		// Weights of one half are above the default threshold and count towards trust.
+		ParatensorModule::epoch( netuid, 0, false );
+		assert_eq!( ParatensorModule::get_trust( netuid, 0 ), u16::MAX );
+		assert_eq!( ParatensorModule::get_trust( netuid, 1 ), u16::MAX );
+
+		// Raising the threshold above one half clips both weights.
+		assert_ok!( ParatensorModule::sudo_set_trust_threshold( Origin::root(), netuid, u16::MAX ) );
+		ParatensorModule::epoch( netuid, 0, false );
+		assert_eq!( ParatensorModule::get_trust( netuid, 0 ), 0 );
+		assert_eq!( ParatensorModule::get_trust( netuid, 1 ), 0 );
+	});
+}
+
+#[test]
+fn test_epoch_bonds_moving_average() {
+	new_test_ext().execute_with(|| {
+		let netuid: u16 = 1;
+		add_network( netuid, 0, 0 );
+		register_ok_neuron( netuid, 1, 667, 0 );
+		register_ok_neuron( netuid, 2, 668, 300000 );
+		ParatensorModule::add_stake_to_neuron_hotkey_account( &1, 1000 );
+		assert_ok!( ParatensorModule::set_weights( Origin::signed(1), netuid, vec![1], vec![1] ) );
+
+		// Keeping all of the previous bonds leaves them at zero.
+		assert_ok!( ParatensorModule::sudo_set_bonds_moving_average( Origin::root(), netuid, 1_000_000 ) );
+		ParatensorModule::epoch( netuid, 0, false );
+		assert_eq!( ParatensorModule::get_bonds_for_neuron( netuid, 0 ), vec![] );
+
+		// Keeping none of the previous bonds sets them to the weights.
+		assert_ok!( ParatensorModule::sudo_set_bonds_moving_average( Origin::root(), netuid, 0 ) );
+		ParatensorModule::epoch( netuid, 0, false );
+		assert_eq!( ParatensorModule::get_bonds_for_neuron( netuid, 0 ), vec![ (1, u16::MAX) ] );
+	});
+}
+
+#[test]
+fn test_epoch_consensus_follows_kappa() {
+	new_test_ext().execute_with(|| {
+		let netuid: u16 = 1;
+		add_network( netuid, 0, 0 );
+		register_ok_neuron( netuid, 1, 667, 0 );
+		register_ok_neuron( netuid, 2, 668, 300000 );
+		ParatensorModule::add_stake_to_neuron_hotkey_account( &1, 1000 );
+		assert_ok!( ParatensorModule::set_weights( Origin::signed(1), netuid, vec![1], vec![1] ) );
+
+		// Uid 0 has no trust, its consensus falls as kappa rises.
+		ParatensorModule::epoch( netuid, 0, false );
+		let consensus_before: u16 = ParatensorModule::get_consensus( netuid, 0 );
+		assert_ok!( ParatensorModule::sudo_set_kappa( Origin::root(), netuid, u16::MAX ) );
+		ParatensorModule::epoch( netuid, 0, false );
+		assert!( ParatensorModule::get_consensus( netuid, 0 ) < consensus_before );
+	});
+}
+
+#[test]
+fn test_epoch_clears_bonds_in_pruned_uids() {
+	new_test_ext().execute_with(|| {
+		let netuid: u16 = 1;
+		add_network( netuid, 0, 0 );
+		register_ok_neuron( netuid, 1, 667, 0 );
+		register_ok_neuron( netuid, 2, 668, 300000 );
+		let half: I32F32 = I32F32::from_num( 0.5 );
+		ParatensorModule::set_bonds_row( netuid, 0, &vec![ half, half ] );
+		ParatensorModule::set_bonds_row( netuid, 1, &vec![ half, half ] );
+
+		// Queuing a uid twice records it once.
+		ParatensorModule::add_uid_to_prune( netuid, 1 );
+		ParatensorModule::add_uid_to_prune( netuid, 1 );
+		assert_eq!( ParatensorModule::uids_to_prune( netuid ), vec![ 1 ] );
+
+		// The epoch consumes the set and no neuron keeps a bond in uid 1.
+		ParatensorModule::epoch( netuid, 0, false );
+		assert_eq!( ParatensorModule::uids_to_prune( netuid ), vec![] );
+		for uid_i in 0..2 {
+			let bonds: Vec<(u16, u16)> = ParatensorModule::get_bonds_for_neuron( netuid, uid_i );
+			assert!( bonds.iter().all( |( uid_j, _ )| *uid_j != 1 ) );
+			assert!( bonds.iter().any( |( uid_j, _ )| *uid_j == 0 ) );
+		}
+	});
+}
+
+#[test]
+fn test_reset_bonds() {
+	new_test_ext().execute_with(|| {
+		let netuid: u16 = 1;
+		add_network( netuid, 0, 0 );
+		register_ok_neuron( netuid, 1, 667, 0 );
+		register_ok_neuron( netuid, 2, 668, 300000 );
+		let half: I32F32 = I32F32::from_num( 0.5 );
+		ParatensorModule::set_bonds_row( netuid, 0, &vec![ half, half ] );
+		ParatensorModule::add_uid_to_prune( netuid, 1 );
+
+		assert_ok!( ParatensorModule::reset_bonds( Origin::root(), netuid ) );
+		assert_eq!( ParatensorModule::get_bonds_for_neuron( netuid, 0 ), vec![] );
+		assert_eq!( ParatensorModule::uids_to_prune( netuid ), vec![] );
+	});
+}
+
+#[test]
+fn test_reset_bonds_not_root() {
+	new_test_ext().execute_with(|| {
+		add_network( 1, 0, 0 );
+		let result = ParatensorModule::reset_bonds( Origin::signed(1), 1 );
+		assert_eq!( result, Err(DispatchError::BadOrigin.into()) );
+	});
+}
+
+#[test]
+fn test_reset_bonds_network_does_not_exist() {
+	new_test_ext().execute_with(|| {
+		let result = ParatensorModule::reset_bonds( Origin::root(), 1 );
+		assert_eq!( result, Err(Error::<Test>::NetworkDoesNotExist.into()) );
+	});
+}
+
+#[test]
+fn test_epoch_excludes_inactive_neurons() {
+	new_test_ext().execute_with(|| {
+		let netuid: u16 = 1;
+		add_network( netuid, 0, 0 );
+		register_ok_neuron( netuid, 1, 667, 0 );
+		register_ok_neuron( netuid, 2, 668, 300000 );
+		ParatensorModule::add_stake_to_neuron_hotkey_account( &1, 1000 );
+		assert_ok!( ParatensorModule::set_weights( Origin::signed(1), netuid, vec![1], vec![1] ) );
+		assert_ok!( ParatensorModule::sudo_set_activity_cutoff( Origin::root(), netuid, 10 ) );
+
+		// Past the activity cutoff the weights no longer count towards ranks.
+		System::set_block_number( 20 );
+		ParatensorModule::epoch( netuid, 0, false );
+		assert_eq!( ParatensorModule::get_active( netuid ), vec![ false, false ] );
+		assert_eq!( ParatensorModule::get_ranks( netuid, 1 ), 0 );
+
+		// Setting weights again makes the neuron active.
+		assert_ok!( ParatensorModule::set_weights( Origin::signed(1), netuid, vec![1], vec![1] ) );
+		ParatensorModule::epoch( netuid, 0, false );
+		assert_eq!( ParatensorModule::get_active( netuid ), vec![ true, false ] );
+		assert_eq!( ParatensorModule::get_ranks( netuid, 1 ), u16::MAX );
+	});
+}
+
+#[test]
+fn test_migrate_to_last_update() {
+	new_test_ext().execute_with(|| {
+		let netuid: u16 = 1;
+		add_network( netuid, 0, 0 );
+		register_ok_neuron( netuid, 1, 667, 0 );
+		register_ok_neuron( netuid, 2, 668, 300000 );
+		System::set_block_number( 50 );
+		StorageVersion::new( 11 ).put::<ParatensorModule>();
+
+		ParatensorModule::migrate_to_v12_last_update();
+		assert_eq!( ParatensorModule::get_last_update_for_uid( netuid, 0 ), 50 );
+		assert_eq!( ParatensorModule::get_last_update_for_uid( netuid, 1 ), 50 );
+		assert_eq!( ParatensorModule::on_chain_storage_version(), 12 );
+	});
+}
+
+#[test]
+fn test_epoch_validator_permit() {
+	new_test_ext().execute_with(|| {
+		let netuid: u16 = 1;
+		add_network( netuid, 0, 0 );
+		register_ok_neuron( netuid, 1, 667, 0 );
+		register_ok_neuron( netuid, 2, 668, 300000 );
+		ParatensorModule::add_stake_to_neuron_hotkey_account( &1, 100 );
+		ParatensorModule::add_stake_to_neuron_hotkey_account( &2, 1000 );
+		assert_ok!( ParatensorModule::sudo_set_max_allowed_validators( Origin::root(), netuid, 1 ) );
+		assert_ok!( ParatensorModule::set_weights( Origin::signed(1), netuid, vec![1], vec![1] ) );
+		assert_ok!( ParatensorModule::set_weights( Origin::signed(2), netuid, vec![0], vec![1] ) );
+
+		// Only the neuron with the most stake holds a permit, the weights of the other are ignored.
+		ParatensorModule::epoch( netuid, 0, false );
+		assert_eq!( ParatensorModule::get_validator_permit( netuid ), vec![ false, true ] );
+		assert_eq!( ParatensorModule::get_ranks( netuid, 0 ), u16::MAX );
+		assert_eq!( ParatensorModule::get_ranks( netuid, 1 ), 0 );
+		assert_eq!( ParatensorModule::get_bonds_for_neuron( netuid, 0 ), vec![] );
+		assert!( ParatensorModule::get_bonds_for_neuron( netuid, 1 ).len() > 0 );
+		assert_eq!( ParatensorModule::get_neuron_info( netuid, 1 ).unwrap().validator_permit, true );
+	});
+}
+
+#[test]
+fn test_is_topk() {
+	new_test_ext().execute_with(|| {
+		let x: Vec<I32F32> = vec![ I32F32::from_num( 1 ), I32F32::from_num( 3 ), I32F32::from_num( 2 ), I32F32::from_num( 3 ) ];
+		assert_eq!( ParatensorModule::is_topk( &x, 0 ), vec![ false, false, false, false ] );
+		assert_eq!( ParatensorModule::is_topk( &x, 1 ), vec![ false, true, false, false ] );
+		assert_eq!( ParatensorModule::is_topk( &x, 3 ), vec![ false, true, true, true ] );
+		assert_eq!( ParatensorModule::is_topk( &x, 10 ), vec![ true, true, true, true ] );
+	});
+}
@@ -101,12 +101,17 @@ parameter_types! {
 	pub const TransactionByteFee: Balance = 100;
 	pub const SDebug:u64 = 1;
 	pub const InitialRho: u16 = 10;
-	pub const InitialKappa: u16 = 2;
+	pub const InitialKappa: u16 = 32_767;
 	pub const InitialTempo: u16 = 0;
 	pub const SelfOwnership: u64 = 2;
 	pub const InitialImmunityPeriod: u16 = 2;
 	pub const InitialMaxAllowedUids: u16 = 2;
 	pub const InitialBondsMovingAverage: u64 = 500_000;
+	pub const InitialTrustThreshold: u16 = 655;
+	pub const InitialMaxAllowedValidators: u16 = 2;
+	pub const InitialDelegateTake: u16 = 11_796;
+	pub const InitialUnbondingPeriod: u64 = 10;
+	pub const MaxUnbondingChunks: u32 = 3;
 	pub const InitialIncentivePruningDenominator: u16 = 1;
 	pub const InitialStakePruningDenominator: u16 = 1;
 	pub const InitialStakePruningMin: u16 = 0;
@@ -128,6 +133,55 @@ parameter_types! {
 	pub const InitialMaxRegistrationsPerBlock: u16 = 3;
 	pub const InitialTargetRegistrationsPerInterval: u16 = 2;
 	pub const InitialPrunningScore : u16 = u16::MAX;
+	pub const InitialServingRateLimit: u64 = 0;
+	pub const MinimumBlocksPerStep: u64 = 1;
+	pub const MaximumBlocksPerStep: u64 = 100;
+	pub const MinimumTempo: u16 = 0;
+	pub const MaximumTempo: u16 = 100;
+	pub const MinimumAdjustmentInterval: u16 = 1;
+	pub const MaximumAdjustmentInterval: u16 = 1000;
+	pub const MinimumTargetRegistrationsPerInterval: u16 = 1;
+	pub const MaximumTargetRegistrationsPerInterval: u16 = 100;
+	pub const MinimumMaxRegistrationsPerBlock: u16 = 1;
+	pub const MaximumMaxRegistrationsPerBlock: u16 = 100;
+	pub const MinimumActivityCutoff: u16 = 1;
+	pub const MaximumActivityCutoff: u16 = u16::MAX;
+	pub const MinimumRho: u16 = 1;
+	pub const MaximumRho: u16 = 20;
+	pub const MinimumKappa: u16 = 1;
+	pub const MaximumKappa: u16 = u16::MAX;
+	pub const MinimumMaxAllowedUids: u16 = 1;
+	pub const MaximumMaxAllowedUids: u16 = 100;
+	pub const MinimumMinAllowedWeights: u16 = 0;
+	pub const MaximumMinAllowedWeights: u16 = 100;
+	pub const MinimumMaxAllowedMaxMinRatio: u16 = 0;
+	pub const MaximumMaxAllowedMaxMinRatio: u16 = u16::MAX;
+	pub const MinimumValidatorBatchSize: u16 = 1;
+	pub const MaximumValidatorBatchSize: u16 = 100;
+	pub const MinimumValidatorSequenceLength: u16 = 1;
+	pub const MaximumValidatorSequenceLength: u16 = 100;
+	pub const MinimumValidatorEpochLength: u16 = 1;
+	pub const MaximumValidatorEpochLength: u16 = u16::MAX;
+	pub const MinimumValidatorEpochsPerReset: u16 = 1;
+	pub const MaximumValidatorEpochsPerReset: u16 = u16::MAX;
+	pub const MinimumIncentivePruningDenominator: u16 = 0;
+	pub const MaximumIncentivePruningDenominator: u16 = u16::MAX;
+	pub const MinimumStakePruningDenominator: u16 = 0;
+	pub const MaximumStakePruningDenominator: u16 = u16::MAX;
+	pub const MinimumStakePruningMin: u16 = 0;
+	pub const MaximumStakePruningMin: u16 = u16::MAX;
+	pub const MinimumImmunityPeriod: u16 = 0;
+	pub const MaximumImmunityPeriod: u16 = 100;
+	pub const MinimumBondsMovingAverage: u64 = 0;
+	pub const MaximumBondsMovingAverage: u64 = 1_000_000;
+	pub const MinimumTrustThreshold: u16 = 0;
+	pub const MaximumTrustThreshold: u16 = u16::MAX;
+	pub const MinimumMaxAllowedValidators: u16 = 1;
+	pub const MaximumMaxAllowedValidators: u16 = 100;
+	pub const MinimumDelegateTake: u16 = 0;
+	pub const MaximumDelegateTake: u16 = 32_767;
+	pub const MinimumUnbondingPeriod: u64 = 0;
+	pub const MaximumUnbondingPeriod: u64 = 1000;
 }
 impl pallet_paratensor::Config for Test {
 	type Event = Event;
@@ -140,10 +194,18 @@ impl pallet_paratensor::Config for Test {
 	type InitialBlocksPerStep = InitialBlocksPerStep;
 	type InitialTempo = InitialTempo;
 	type InitialDifficulty = InitialDifficulty;
+	type MinimumDifficulty = MinimumDifficulty;
+	type MaximumDifficulty = MaximumDifficulty;
 	type InitialAdjustmentInterval = InitialAdjustmentInterval;
 	type InitialTargetRegistrationsPerInterval = InitialTargetRegistrationsPerInterval;
 	type InitialRho = InitialRho;
 	type InitialKappa = InitialKappa;
+	type InitialBondsMovingAverage = InitialBondsMovingAverage;
+	type InitialTrustThreshold = InitialTrustThreshold;
+	type InitialMaxAllowedValidators = InitialMaxAllowedValidators;
+	type InitialDelegateTake = InitialDelegateTake;
+	type InitialUnbondingPeriod = InitialUnbondingPeriod;
+	type MaxUnbondingChunks = MaxUnbondingChunks;
 	type InitialMaxAllowedUids = InitialMaxAllowedUids;
 	type InitialValidatorBatchSize = InitialValidatorBatchSize;
 	type InitialValidatorSequenceLen = InitialValidatorSequenceLen;
@@ -156,6 +218,55 @@ impl pallet_paratensor::Config for Test {
 	type InitialActivityCutoff = InitialActivityCutoff;
 	type InitialMaxRegistrationsPerBlock = InitialMaxRegistrationsPerBlock;
 	type InitialPrunningScore = InitialPrunningScore;
+	type InitialServingRateLimit = InitialServingRateLimit;
+	type MinimumBlocksPerStep = MinimumBlocksPerStep;
+	type MaximumBlocksPerStep = MaximumBlocksPerStep;
+	type MinimumTempo = MinimumTempo;
+	type MaximumTempo = MaximumTempo;
+	type MinimumAdjustmentInterval = MinimumAdjustmentInterval;
+	type MaximumAdjustmentInterval = MaximumAdjustmentInterval;
+	type MinimumTargetRegistrationsPerInterval = MinimumTargetRegistrationsPerInterval;
+	type MaximumTargetRegistrationsPerInterval = MaximumTargetRegistrationsPerInterval;
+	type MinimumMaxRegistrationsPerBlock = MinimumMaxRegistrationsPerBlock;
+	type MaximumMaxRegistrationsPerBlock = MaximumMaxRegistrationsPerBlock;
+	type MinimumActivityCutoff = MinimumActivityCutoff;
+	type MaximumActivityCutoff = MaximumActivityCutoff;
+	type MinimumRho = MinimumRho;
+	type MaximumRho = MaximumRho;
+	type MinimumKappa = MinimumKappa;
+	type MaximumKappa = MaximumKappa;
+	type MinimumMaxAllowedUids = MinimumMaxAllowedUids;
+	type MaximumMaxAllowedUids = MaximumMaxAllowedUids;
+	type MinimumMinAllowedWeights = MinimumMinAllowedWeights;
+	type MaximumMinAllowedWeights = MaximumMinAllowedWeights;
+	type MinimumMaxAllowedMaxMinRatio = MinimumMaxAllowedMaxMinRatio;
+	type MaximumMaxAllowedMaxMinRatio = MaximumMaxAllowedMaxMinRatio;
+	type MinimumValidatorBatchSize = MinimumValidatorBatchSize;
+	type MaximumValidatorBatchSize = MaximumValidatorBatchSize;
+	type MinimumValidatorSequenceLength = MinimumValidatorSequenceLength;
+	type MaximumValidatorSequenceLength = MaximumValidatorSequenceLength;
+	type MinimumValidatorEpochLength = MinimumValidatorEpochLength;
+	type MaximumValidatorEpochLength = MaximumValidatorEpochLength;
+	type MinimumValidatorEpochsPerReset = MinimumValidatorEpochsPerReset;
+	type MaximumValidatorEpochsPerReset = MaximumValidatorEpochsPerReset;
+	type MinimumIncentivePruningDenominator = MinimumIncentivePruningDenominator;
+	type MaximumIncentivePruningDenominator = MaximumIncentivePruningDenominator;
+	type MinimumStakePruningDenominator = MinimumStakePruningDenominator;
+	type MaximumStakePruningDenominator = MaximumStakePruningDenominator;
+	type MinimumStakePruningMin = MinimumStakePruningMin;
+	type MaximumStakePruningMin = MaximumStakePruningMin;
+	type MinimumImmunityPeriod = MinimumImmunityPeriod;
+	type MaximumImmunityPeriod = MaximumImmunityPeriod;
+	type MinimumBondsMovingAverage = MinimumBondsMovingAverage;
+	type MaximumBondsMovingAverage = MaximumBondsMovingAverage;
+	type MinimumTrustThreshold = MinimumTrustThreshold;
+	type MaximumTrustThreshold = MaximumTrustThreshold;
+	type MinimumMaxAllowedValidators = MinimumMaxAllowedValidators;
+	type MaximumMaxAllowedValidators = MaximumMaxAllowedValidators;
+	type MinimumDelegateTake = MinimumDelegateTake;
+	type MaximumDelegateTake = MaximumDelegateTake;
+	type MinimumUnbondingPeriod = MinimumUnbondingPeriod;
+	type MaximumUnbondingPeriod = MaximumUnbondingPeriod;
 }
 
 // Build genesis storage according to the mock runtime.
@@ -201,4 +312,10 @@ pub fn register_ok_neuron( netuid: u16, hotkey_account_id: u64, coldkey_account_
 	let (nonce, work): (u64, Vec<u8>) = ParatensorModule::create_work_for_block_number( netuid, block_number, start_nonce );
 	let result = ParatensorModule::register( <<Test as frame_system::Config>::Origin>::signed(hotkey_account_id), netuid, block_number, nonce, work, hotkey_account_id, coldkey_account_id );
 	assert_ok!(result);
-}
\ No newline at end of file
+}
+
+#[allow(dead_code)]
+pub fn add_network( netuid: u16, tempo: u16, modality: u16 ) {
+	let result = ParatensorModule::sudo_add_network( <<Test as frame_system::Config>::Origin>::root(), netuid, tempo, modality );
+	assert_ok!(result);
+}
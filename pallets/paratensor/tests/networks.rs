@@ -0,0 +1,140 @@
+use pallet_paratensor::{Error};
+use frame_support::{assert_ok};
+use frame_system::Config;
+use crate::{mock::*};
+use frame_support::sp_runtime::DispatchError;
+use frame_support::traits::{GetStorageVersion, StorageVersion};
+
+mod mock;
+
+/********************************************
+	networks::sudo_add_network() tests
+*********************************************/
+#[test]
+fn test_add_network_ok() {
+	new_test_ext().execute_with(|| {
+		let netuid: u16 = 1;
+		assert_eq!( ParatensorModule::if_subnet_exist( netuid ), false );
+		assert_ok!( ParatensorModule::sudo_add_network( <<Test as Config>::Origin>::root(), netuid, 5, 2 ) );
+		assert_eq!( ParatensorModule::if_subnet_exist( netuid ), true );
+		assert_eq!( ParatensorModule::get_tempo( netuid ), 5 );
+		assert_eq!( ParatensorModule::get_network_modality( netuid ), 2 );
+	});
+}
+
+#[test]
+fn test_add_network_already_exists() {
+	new_test_ext().execute_with(|| {
+		add_network( 1, 0, 0 );
+		let result = ParatensorModule::sudo_add_network( <<Test as Config>::Origin>::root(), 1, 0, 0 );
+		assert_eq!( result, Err(Error::<Test>::NetworkExist.into()) );
+	});
+}
+
+#[test]
+fn test_add_network_invalid_modality() {
+	new_test_ext().execute_with(|| {
+		let result = ParatensorModule::sudo_add_network( <<Test as Config>::Origin>::root(), 1, 0, 3 );
+		assert_eq!( result, Err(Error::<Test>::InvalidModality.into()) );
+	});
+}
+
+#[test]
+fn test_add_network_not_root() {
+	new_test_ext().execute_with(|| {
+		let result = ParatensorModule::sudo_add_network( <<Test as Config>::Origin>::signed(0), 1, 0, 0 );
+		assert_eq!( result, Err(DispatchError::BadOrigin.into()) );
+	});
+}
+
+/********************************************
+	networks::sudo_remove_network() tests
+*********************************************/
+#[test]
+fn test_remove_network_does_not_exist() {
+	new_test_ext().execute_with(|| {
+		let result = ParatensorModule::sudo_remove_network( <<Test as Config>::Origin>::root(), 1 );
+		assert_eq!( result, Err(Error::<Test>::NetworkDoesNotExist.into()) );
+	});
+}
+
+#[test]
+fn test_remove_network_cleans_up() {
+	new_test_ext().execute_with(|| {
+		add_network( 1, 0, 0 );
+		add_network( 2, 0, 0 );
+		register_ok_neuron( 1, 1, 667, 0 );
+		register_ok_neuron( 1, 2, 668, 300000 );
+		register_ok_neuron( 2, 2, 668, 212312414 );
+		assert_ok!( ParatensorModule::set_weights( <<Test as Config>::Origin>::signed(1), 1, vec![0, 1], vec![1, 1] ) );
+		ParatensorModule::set_ranks( 1, 0, 10 );
+
+		assert_ok!( ParatensorModule::sudo_remove_network( <<Test as Config>::Origin>::root(), 1 ) );
+		assert_eq!( ParatensorModule::if_subnet_exist( 1 ), false );
+		assert_eq!( ParatensorModule::get_subnetwork_n( 1 ), 0 );
+		assert_eq!( ParatensorModule::is_hotkey_subnetwork_active( 1, &1 ), false );
+		assert_eq!( ParatensorModule::get_weights_for_neuron( 1, 0 ), vec![] );
+		assert_eq!( ParatensorModule::get_ranks( 1, 0 ), 0 );
+
+		// Hotkey 1 was only on network 1, hotkey 2 keeps its registration on network 2.
+		assert_eq!( ParatensorModule::is_hotkey_active( &1 ), false );
+		assert_eq!( ParatensorModule::get_subnets_for_hotkey( 2 ), vec![2] );
+		assert_eq!( ParatensorModule::is_hotkey_subnetwork_active( 2, &2 ), true );
+	});
+}
+
+#[test]
+fn test_remove_network_not_root() {
+	new_test_ext().execute_with(|| {
+		add_network( 1, 0, 0 );
+		let result = ParatensorModule::sudo_remove_network( <<Test as Config>::Origin>::signed(0), 1 );
+		assert_eq!( result, Err(DispatchError::BadOrigin.into()) );
+	});
+}
+
+/********************************************
+	calls on networks which do not exist
+*********************************************/
+#[test]
+fn test_register_network_does_not_exist() {
+	new_test_ext().execute_with(|| {
+		let (nonce, work): (u64, Vec<u8>) = ParatensorModule::create_work_for_block_number( 1, 0, 3942084 );
+		let result = ParatensorModule::register( <<Test as Config>::Origin>::signed(1), 1, 0, nonce, work, 1, 667 );
+		assert_eq!( result, Err(Error::<Test>::NetworkDoesNotExist.into()) );
+	});
+}
+
+#[test]
+fn test_set_weights_network_does_not_exist() {
+	new_test_ext().execute_with(|| {
+		let result = ParatensorModule::set_weights( <<Test as Config>::Origin>::signed(1), 1, vec![0], vec![1] );
+		assert_eq!( result, Err(Error::<Test>::NetworkDoesNotExist.into()) );
+	});
+}
+
+#[test]
+fn test_stake_after_network_removed() {
+	new_test_ext().execute_with(|| {
+		add_network( 1, 0, 0 );
+		register_ok_neuron( 1, 1, 667, 0 );
+		assert_ok!( ParatensorModule::sudo_remove_network( <<Test as Config>::Origin>::root(), 1 ) );
+		let result = ParatensorModule::add_stake( <<Test as Config>::Origin>::signed(667), 1, 10 );
+		assert_eq!( result, Err(Error::<Test>::NotRegistered.into()) );
+	});
+}
+
+#[test]
+fn test_migrate_to_networks_added() {
+	new_test_ext().execute_with(|| {
+		// A network which has neurons but was never added to the registry.
+		ParatensorModule::add_subnetwork_account( 1, 0, &10 );
+		StorageVersion::new( 7 ).put::<ParatensorModule>();
+		assert_eq!( ParatensorModule::if_subnet_exist( 1 ), false );
+
+		ParatensorModule::migrate_to_v8_networks_added();
+		assert_eq!( ParatensorModule::if_subnet_exist( 1 ), true );
+		assert_eq!( ParatensorModule::if_subnet_exist( 2 ), false );
+		assert_eq!( ParatensorModule::get_network_modality( 1 ), 0 );
+		assert_eq!( ParatensorModule::on_chain_storage_version(), 8 );
+	});
+}
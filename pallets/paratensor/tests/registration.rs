@@ -6,6 +6,10 @@ use crate::{mock::*};
 use frame_support::sp_runtime::DispatchError;
 use frame_support::dispatch::{GetDispatchInfo, DispatchInfo};
 use frame_support::weights::{DispatchClass, Pays};
+use frame_support::storage::migration::{get_storage_value, put_storage_value};
+use frame_support::traits::{GetStorageVersion, StorageVersion};
+use frame_support::{Blake2_128Concat, StorageHasher};
+use codec::Encode;
 
 mod mock;
 
@@ -41,6 +45,7 @@ fn test_registration_difficulty() {
 #[test]
 fn test_registration_repeat_work() {
 	new_test_ext().execute_with(|| {
+		add_network( 1, 0, 0 );
 		let block_number: u64 = 0;
 		let netuid: u16 = 1;
 		let hotkey_account_id_1 = 1;
@@ -56,6 +61,7 @@ fn test_registration_repeat_work() {
 #[test]
 fn test_registration_ok() {
 	new_test_ext().execute_with(|| {
+		add_network( 1, 0, 0 );
 		let block_number: u64 = 0;
 		let netuid: u16 = 1;
 		let (nonce, work): (u64, Vec<u8>) = ParatensorModule::create_work_for_block_number( netuid, block_number, 129123813);
@@ -71,8 +77,8 @@ fn test_registration_ok() {
 		//check if hotkey is added to the Hotkeys
 		assert_eq!(ParatensorModule::get_coldkey_for_hotkey(&hotkey_account_id), coldkey_account_id);
 
-		//check if coldkey is added to coldkeys
-		assert_eq!(ParatensorModule::get_hotkey_for_coldkey(&coldkey_account_id), hotkey_account_id);
+		//check if hotkey is added to the hotkeys owned by the coldkey
+		assert_eq!(ParatensorModule::get_owned_hotkeys(&coldkey_account_id), vec![hotkey_account_id]);
 
 		// Check the list of neworks that uid has registered 
 		let subs = ParatensorModule::get_subnets_for_hotkey(hotkey_account_id);
@@ -93,9 +99,11 @@ fn test_registration_ok() {
 #[test]
 fn test_registration_too_many_registrations_per_block() {
 	new_test_ext().execute_with(|| {
+		add_network( 1, 0, 0 );
 		
 		let netuid: u16 = 1;
-		ParatensorModule::set_max_registratations_per_block( 10 );
+		ParatensorModule::set_max_registratations_per_block( netuid, 10 );
+		assert_ok!(ParatensorModule::sudo_set_max_allowed_uids(<<Test as Config>::Origin>::root(), netuid, 11));
 
 		let block_number: u64 = 0;
 		let (nonce0, work0): (u64, Vec<u8>) = ParatensorModule::create_work_for_block_number( netuid, block_number, 3942084);
@@ -127,6 +135,44 @@ fn test_registration_too_many_registrations_per_block() {
 	});
 }
 
+#[test]
+fn test_registration_this_block_resets_each_block() {
+	new_test_ext().execute_with(|| {
+		add_network( 1, 0, 0 );
+		let netuid: u16 = 1;
+		ParatensorModule::set_max_registratations_per_block( netuid, 1 );
+		register_ok_neuron( netuid, 1, 1, 3942084 );
+		assert_eq!( ParatensorModule::get_registrations_this_block( netuid ), 1 );
+		let (nonce, work): (u64, Vec<u8>) = ParatensorModule::create_work_for_block_number( netuid, 0, 11231312312 );
+		let result = ParatensorModule::register(<<Test as Config>::Origin>::signed(2), netuid, 0, nonce, work, 2, 2);
+		assert_eq!( result, Err(Error::<Test>::ToManyRegistrationsThisBlock.into()) );
+
+		// The counter is cleared by the next block.
+		step_block( 1 );
+		assert_eq!( ParatensorModule::get_registrations_this_block( netuid ), 0 );
+		register_ok_neuron( netuid, 2, 2, 11231312312 );
+	});
+}
+
+#[test]
+fn test_registration_per_block_limit_is_per_subnet() {
+	new_test_ext().execute_with(|| {
+		add_network( 1, 0, 0 );
+		add_network( 2, 0, 0 );
+		let netuid: u16 = 1;
+		let netuid2: u16 = 2;
+		ParatensorModule::set_max_registratations_per_block( netuid, 1 );
+		register_ok_neuron( netuid, 1, 1, 3942084 );
+		let (nonce, work): (u64, Vec<u8>) = ParatensorModule::create_work_for_block_number( netuid, 0, 11231312312 );
+		let result = ParatensorModule::register(<<Test as Config>::Origin>::signed(2), netuid, 0, nonce, work, 2, 2);
+		assert_eq!( result, Err(Error::<Test>::ToManyRegistrationsThisBlock.into()) );
+
+		// A busy subnet does not block registrations on another subnet.
+		register_ok_neuron( netuid2, 2, 2, 212312414 );
+		assert_eq!( ParatensorModule::get_registrations_this_block( netuid2 ), 1 );
+	});
+}
+
 #[test]
 fn test_registration_defaults() {
 	new_test_ext().execute_with(|| { /*TO DO: step_block calls on_initialize in the hooks that is not fully implemented yet */
@@ -154,17 +200,108 @@ fn test_registration_defaults() {
 
 #[test]
 fn test_registration_difficulty_adjustment() {
-	/*TO DO */
+	new_test_ext().execute_with(|| {
+		add_network( 1, 0, 0 );
+		let netuid: u16 = 1;
+		assert_ok!(ParatensorModule::sudo_set_max_allowed_uids(<<Test as Config>::Origin>::root(), netuid, 10));
+		register_ok_neuron( netuid, 1, 1, 3942084 );
+		register_ok_neuron( netuid, 2, 2, 11231312312 );
+		register_ok_neuron( netuid, 3, 3, 212312414 );
+		assert_eq!( ParatensorModule::get_registrations_this_interval( netuid ), 3 );
+
+		// Three registrations against a target of two raises the difficulty.
+		step_block( 100 );
+		assert_eq!( ParatensorModule::get_difficulty_as_u64( netuid ), 12500 );
+		assert_eq!( ParatensorModule::get_registrations_this_interval( netuid ), 0 );
+
+		// No registrations lowers the difficulty, clamped to the minimum.
+		step_block( 100 );
+		assert_eq!( ParatensorModule::get_difficulty_as_u64( netuid ), 10000 );
+	});
 }
 
 #[test]
 fn test_registration_immunity_period() {
-	/* TO DO */
+	new_test_ext().execute_with(|| {
+		add_network( 1, 0, 0 );
+		let netuid: u16 = 1;
+		register_ok_neuron( netuid, 1, 1, 3942084 );
+		register_ok_neuron( netuid, 2, 2, 11231312312 );
+
+		// The network is full and both neurons are immune.
+		let (nonce, work): (u64, Vec<u8>) = ParatensorModule::create_work_for_block_number( netuid, 0, 212312414 );
+		let result = ParatensorModule::register(<<Test as Config>::Origin>::signed(3), netuid, 0, nonce, work, 3, 3);
+		assert_eq!( result, Err(Error::<Test>::AllNeuronsImmune.into()) );
+		assert_eq!( ParatensorModule::get_neuron_to_prune( netuid ), None );
+
+		// Once the immunity period has passed a neuron can be replaced.
+		step_block( ParatensorModule::get_immunity_period( netuid ) );
+		register_ok_neuron( netuid, 3, 3, 212312414 );
+		assert_eq!( ParatensorModule::is_hotkey_subnetwork_active( netuid, &3 ), true );
+	});
+}
+
+#[test]
+fn test_registration_prune_skips_immune_neurons() {
+	new_test_ext().execute_with(|| {
+		add_network( 1, 0, 0 );
+		let netuid: u16 = 1;
+		assert_ok!(ParatensorModule::sudo_set_max_allowed_uids(<<Test as Config>::Origin>::root(), netuid, 3));
+		register_ok_neuron( netuid, 1, 1, 3942084 );
+		step_block( 1 );
+		register_ok_neuron( netuid, 2, 2, 11231312312 );
+		step_block( ParatensorModule::get_immunity_period( netuid ) );
+		register_ok_neuron( netuid, 3, 3, 212312414 );
+		let uid1: u16 = ParatensorModule::get_neuron_for_net_and_hotkey( netuid, &1 );
+		let uid2: u16 = ParatensorModule::get_neuron_for_net_and_hotkey( netuid, &2 );
+		let uid3: u16 = ParatensorModule::get_neuron_for_net_and_hotkey( netuid, &3 );
+
+		// The freshly registered neuron has the lowest score but is still immune.
+		ParatensorModule::set_prunning_score( netuid, uid1, 110 );
+		ParatensorModule::set_prunning_score( netuid, uid2, 100 );
+		ParatensorModule::set_prunning_score( netuid, uid3, 0 );
+		assert_eq!( ParatensorModule::get_neuron_to_prune( netuid ), Some( uid2 ) );
+
+		// Ties are broken by the oldest registration.
+		ParatensorModule::set_prunning_score( netuid, uid1, 100 );
+		assert_eq!( ParatensorModule::get_neuron_to_prune( netuid ), Some( uid1 ) );
+	});
+}
+
+#[test]
+fn test_registration_prune_replaces_in_place_above_max_allowed_uids() {
+	new_test_ext().execute_with(|| {
+		add_network( 1, 0, 0 );
+		let netuid: u16 = 1;
+		assert_ok!(ParatensorModule::sudo_set_max_allowed_uids(<<Test as Config>::Origin>::root(), netuid, 3));
+		register_ok_neuron( netuid, 1, 1, 3942084 );
+		register_ok_neuron( netuid, 2, 2, 11231312312 );
+		register_ok_neuron( netuid, 3, 3, 212312414 );
+
+		// The limit ends up below the current network size.
+		ParatensorModule::set_max_allowed_uids( netuid, 2 );
+		step_block( ParatensorModule::get_immunity_period( netuid ) );
+		ParatensorModule::set_prunning_score( netuid, 0, 0 );
+		ParatensorModule::set_prunning_score( netuid, 1, 1 );
+		ParatensorModule::set_prunning_score( netuid, 2, 2 );
+
+		// Each replacement keeps the size and every uid below it.
+		register_ok_neuron( netuid, 4, 4, 21813123 );
+		assert_eq!( ParatensorModule::get_subnetwork_n( netuid ), 3 );
+		assert_eq!( ParatensorModule::get_hotkey_for_net_and_neuron( netuid, 0 ), 4 );
+		assert_eq!( ParatensorModule::get_neuron_for_net_and_hotkey( netuid, &4 ), 0 );
+		assert_eq!( ParatensorModule::is_hotkey_subnetwork_active( netuid, &1 ), false );
+		assert_eq!( ParatensorModule::get_hotkey_for_net_and_neuron( netuid, 2 ), 3 );
+
+		// The epoch runs over the whole network.
+		step_block( 1 );
+	});
 }
 
 #[test]
 fn test_registration_already_active_hotkey() {
 	new_test_ext().execute_with(|| {
+		add_network( 1, 0, 0 );
 
 		let block_number: u64 = 0;
 		let netuid: u16 = 1;
@@ -186,6 +323,7 @@ fn test_registration_already_active_hotkey() {
 #[test]
 fn test_registration_invalid_seal() {
 	new_test_ext().execute_with(|| {
+		add_network( 1, 0, 0 );
 		let block_number: u64 = 0;
 		let netuid:u16 =1;
 		let (nonce, work): (u64, Vec<u8>) = ParatensorModule::create_work_for_block_number( netuid, 1, 0);
@@ -199,6 +337,7 @@ fn test_registration_invalid_seal() {
 #[test]
 fn test_registration_invalid_block_number() {
 	new_test_ext().execute_with(|| {
+		add_network( 1, 0, 0 );
 		let block_number: u64 = 1;
 		let netuid: u16 =1;
 		let (nonce, work): (u64, Vec<u8>) = ParatensorModule::create_work_for_block_number(netuid, block_number, 0);
@@ -212,6 +351,7 @@ fn test_registration_invalid_block_number() {
 #[test]
 fn test_registration_invalid_difficulty() {
 	new_test_ext().execute_with(|| {
+		add_network( 1, 0, 0 );
 		let block_number: u64 = 0;
 		let netuid: u16 = 1;
 		let (nonce, work): (u64, Vec<u8>) = ParatensorModule::create_work_for_block_number( netuid, block_number, 0);
@@ -242,26 +382,190 @@ fn test_registration_failed_no_signature() {
 #[test]
 fn test_registration_get_next_uid() {
 	new_test_ext().execute_with(|| {
-        assert_eq!(ParatensorModule::get_next_uid(), 0); // We start with id 0
-		assert_eq!(ParatensorModule::get_next_uid(), 1); // One up
-		assert_eq!(ParatensorModule::get_next_uid(), 2) // One more
+		add_network( 1, 0, 0 );
+        assert_eq!(ParatensorModule::get_next_uid(1), 0); // We start with id 0
+		register_ok_neuron( 1, 1, 1, 3942084 );
+		assert_eq!(ParatensorModule::get_next_uid(1), 1); // One up
+		assert_eq!(ParatensorModule::get_next_uid(2), 0) // Other networks are unaffected
+	});
+}
+
+#[test]
+fn test_registration_uids_are_per_subnet() {
+	new_test_ext().execute_with(|| {
+		add_network( 1, 0, 0 );
+		add_network( 2, 0, 0 );
+		register_ok_neuron( 1, 1, 1, 3942084 );
+		register_ok_neuron( 1, 2, 2, 11231312312 );
+		register_ok_neuron( 2, 3, 3, 212312414 );
+		assert_eq!( ParatensorModule::get_neuron_for_net_and_hotkey( 1, &1 ), 0 );
+		assert_eq!( ParatensorModule::get_neuron_for_net_and_hotkey( 1, &2 ), 1 );
+		assert_eq!( ParatensorModule::get_neuron_for_net_and_hotkey( 2, &3 ), 0 );
+		assert_eq!( ParatensorModule::get_subnetwork_n( 1 ), 2 );
+		assert_eq!( ParatensorModule::get_subnetwork_n( 2 ), 1 );
+	});
+}
+
+#[test]
+fn test_registration_pruning_keeps_other_subnets() {
+	new_test_ext().execute_with(|| {
+		add_network( 1, 0, 0 );
+		add_network( 2, 0, 0 );
+		register_ok_neuron( 1, 1, 1, 3942084 );
+		register_ok_neuron( 1, 2, 2, 11231312312 );
+		register_ok_neuron( 2, 1, 1, 212312414 );
+		step_block( ParatensorModule::get_immunity_period( 1 ) );
+		ParatensorModule::set_prunning_score( 1, 0, 0 );
+		ParatensorModule::set_prunning_score( 1, 1, 1 );
+
+		// Hotkey 1 is replaced on network 1 but stays registered on network 2.
+		register_ok_neuron( 1, 3, 3, 21813123 );
+		assert_eq!( ParatensorModule::get_subnetwork_n( 1 ), 2 );
+		assert_eq!( ParatensorModule::get_hotkey_for_net_and_neuron( 1, 0 ), 3 );
+		assert_eq!( ParatensorModule::is_hotkey_subnetwork_active( 1, &1 ), false );
+		assert_eq!( ParatensorModule::is_hotkey_subnetwork_active( 2, &1 ), true );
+		assert_eq!( ParatensorModule::get_subnets_for_hotkey( 1 ), vec![2] );
+	});
+}
+
+#[test]
+fn test_migrate_to_dense_uids() {
+	new_test_ext().execute_with(|| {
+		// Uids allocated from the old global counter.
+		ParatensorModule::add_subnetwork_account( 1, 0, &10 );
+		ParatensorModule::add_subnetwork_account( 2, 1, &11 );
+		ParatensorModule::add_subnetwork_account( 2, 2, &12 );
+		ParatensorModule::set_prunning_score( 2, 2, 7 );
+
+		ParatensorModule::migrate_to_v1_dense_uids();
+		assert_eq!( ParatensorModule::get_neuron_for_net_and_hotkey( 1, &10 ), 0 );
+		assert_eq!( ParatensorModule::get_neuron_for_net_and_hotkey( 2, &11 ), 0 );
+		assert_eq!( ParatensorModule::get_neuron_for_net_and_hotkey( 2, &12 ), 1 );
+		assert_eq!( ParatensorModule::get_hotkey_for_net_and_neuron( 2, 1 ), 12 );
+		assert_eq!( ParatensorModule::get_prunning_score( 2, 1 ), 7 );
+		assert_eq!( ParatensorModule::get_subnetwork_n( 2 ), 2 );
+	});
+}
+
+#[test]
+fn test_migrate_block_at_registration_to_double_map() {
+	new_test_ext().execute_with(|| {
+		ParatensorModule::add_subnetwork_account( 1, 0, &10 );
+		ParatensorModule::add_subnetwork_account( 2, 0, &11 );
+		ParatensorModule::add_subnetwork_account( 2, 1, &12 );
+		// Blocks written under the uid keyed layout.
+		put_storage_value( b"ParatensorModule", b"BlockAtRegistration", &0u16.encode(), 5u64 );
+		put_storage_value( b"ParatensorModule", b"BlockAtRegistration", &1u16.encode(), 7u64 );
+		StorageVersion::new( 1 ).put::<ParatensorModule>();
+
+		ParatensorModule::migrate_to_v2_block_at_registration();
+		assert_eq!( ParatensorModule::get_neuron_block_at_registration( 1, 0 ), 5 );
+		assert_eq!( ParatensorModule::get_neuron_block_at_registration( 2, 0 ), 5 );
+		assert_eq!( ParatensorModule::get_neuron_block_at_registration( 2, 1 ), 7 );
+		assert_eq!( ParatensorModule::get_neuron_block_at_registration( 1, 1 ), 0 );
+		assert_eq!( ParatensorModule::on_chain_storage_version(), 2 );
+	});
+}
+
+#[test]
+fn test_migrate_neurons_to_prune_to_set() {
+	new_test_ext().execute_with(|| {
+		// A single uid stored under the old layout.
+		put_storage_value( b"ParatensorModule", b"NeuronsToPruneAtNextEpoch", &1u16.encode(), 3u16 );
+		StorageVersion::new( 2 ).put::<ParatensorModule>();
+
+		ParatensorModule::migrate_to_v3_neurons_to_prune();
+		assert_eq!( ParatensorModule::uids_to_prune( 1 ), vec![ 3 ] );
+		assert_eq!( ParatensorModule::uids_to_prune( 2 ), vec![] );
+		assert_eq!( ParatensorModule::on_chain_storage_version(), 3 );
+	});
+}
+
+#[test]
+fn test_migrate_to_owned_hotkeys() {
+	new_test_ext().execute_with(|| {
+		// Links written under the one to one layout.
+		put_storage_value( b"ParatensorModule", b"Hotkeys", &Blake2_128Concat::hash( &1u64.encode() ), 667u64 );
+		put_storage_value( b"ParatensorModule", b"Hotkeys", &Blake2_128Concat::hash( &2u64.encode() ), 668u64 );
+		put_storage_value( b"ParatensorModule", b"Coldkeys", &Blake2_128Concat::hash( &667u64.encode() ), 1u64 );
+		StorageVersion::new( 3 ).put::<ParatensorModule>();
+
+		ParatensorModule::migrate_to_v4_owned_hotkeys();
+		assert_eq!( ParatensorModule::get_coldkey_for_hotkey( &1 ), 667 );
+		assert_eq!( ParatensorModule::get_coldkey_for_hotkey( &2 ), 668 );
+		assert_eq!( ParatensorModule::get_owned_hotkeys( &667 ), vec![ 1 ] );
+		assert_eq!( ParatensorModule::hotkey_belongs_to_coldkey( &2, &668 ), true );
+		assert_eq!( ParatensorModule::on_chain_storage_version(), 4 );
+	});
+}
+
+#[test]
+fn test_coldkey_owns_many_hotkeys() {
+	new_test_ext().execute_with(|| {
+		add_network( 1, 0, 0 );
+		register_ok_neuron( 1, 1, 667, 0 );
+		register_ok_neuron( 1, 2, 667, 300000 );
+		assert_eq!( ParatensorModule::get_owned_hotkeys( &667 ), vec![ 1, 2 ] );
+		assert_eq!( ParatensorModule::hotkey_belongs_to_coldkey( &1, &667 ), true );
+		assert_eq!( ParatensorModule::hotkey_belongs_to_coldkey( &2, &667 ), true );
+		assert_eq!( ParatensorModule::hotkey_belongs_to_coldkey( &2, &668 ), false );
+
+		// The coldkey can stake to each of its hotkeys.
+		ParatensorModule::add_balance_to_coldkey_account( &667, 200 );
+		assert_ok!( ParatensorModule::add_stake( <<Test as Config>::Origin>::signed(667), 1, 100 ) );
+		assert_ok!( ParatensorModule::add_stake( <<Test as Config>::Origin>::signed(667), 2, 100 ) );
+		assert_eq!( ParatensorModule::get_stake_for_hotkey( &1 ), 100 );
+		assert_eq!( ParatensorModule::get_stake_for_hotkey( &2 ), 100 );
+		assert_ok!( ParatensorModule::remove_stake( <<Test as Config>::Origin>::signed(667), 2, 50 ) );
+		assert_eq!( ParatensorModule::get_stake_for_hotkey( &2 ), 50 );
+
+		// Removing one hotkey keeps the others owned.
+		ParatensorModule::remove_global_account( &1 );
+		assert_eq!( ParatensorModule::get_owned_hotkeys( &667 ), vec![ 2 ] );
+	});
+}
+
+#[test]
+fn test_block_at_registration_per_subnet() {
+	new_test_ext().execute_with(|| {
+		add_network( 1, 0, 0 );
+		add_network( 2, 0, 0 );
+		register_ok_neuron( 1, 0, 0, 3942084 );
+		step_block( 3 );
+		register_ok_neuron( 2, 1, 1, 11231312312 );
+		// Both neurons hold uid 0 on their own network.
+		assert_eq!( ParatensorModule::get_neuron_block_at_registration( 1, 0 ), 0 );
+		assert_eq!( ParatensorModule::get_neuron_block_at_registration( 2, 0 ), 3 );
+		assert_eq!( ParatensorModule::get_neuron_info( 2, 0 ).unwrap().block_at_registration, 3 );
+		assert_eq!( ParatensorModule::get_neuron_info( 2, 0 ).unwrap().hotkey, 1 );
+		assert_eq!( ParatensorModule::get_neuron_info( 2, 1 ), None );
 	});
 }
 
 #[test]
 fn test_registration_get_uid_to_prune() {
 	new_test_ext().execute_with(|| {
+		add_network( 1, 0, 0 );
+		assert_ok!(ParatensorModule::sudo_set_max_allowed_uids(<<Test as Config>::Origin>::root(), 1, 10));
+		ParatensorModule::set_max_registratations_per_block( 1, 10 );
+		register_ok_neuron( 1, 0, 0, 3942084 );
+		register_ok_neuron( 1, 1, 1, 11231312312 );
+		register_ok_neuron( 1, 2, 2, 212312414 );
+		register_ok_neuron( 1, 3, 3, 21813123 );
+		step_block( ParatensorModule::get_immunity_period( 1 ) );
+		ParatensorModule::set_prunning_score(1,0,130);
 		ParatensorModule::set_prunning_score(1,1,100);
 		ParatensorModule::set_prunning_score(1,2,110);
 		ParatensorModule::set_prunning_score(1,3,120);
 		//
-		assert_eq!(ParatensorModule::get_neuron_to_prune(1), 1);
+		assert_eq!(ParatensorModule::get_neuron_to_prune(1), Some(1));
 	});
 }
 
 #[test]
 fn test_registration_pruning() {
 	new_test_ext().execute_with(|| {
+		add_network( 1, 0, 0 );
 		let netuid: u16 = 1;
 		let block_number: u64 = 0;
 		let (nonce0, work0): (u64, Vec<u8>) = ParatensorModule::create_work_for_block_number( netuid, block_number, 3942084);
@@ -269,13 +573,14 @@ fn test_registration_pruning() {
 		let coldkey_account_id = 667;
 		assert_ok!(ParatensorModule::register(<<Test as Config>::Origin>::signed(hotkey_account_id), netuid, block_number, nonce0, work0, hotkey_account_id, coldkey_account_id));
 		let neuron_uid = ParatensorModule::get_neuron_for_net_and_hotkey(netuid, &hotkey_account_id);
-		ParatensorModule::set_prunning_score(netuid, neuron_uid, 2);
 		//
 		let (nonce1, work1): (u64, Vec<u8>) = ParatensorModule::create_work_for_block_number( netuid, block_number, 11231312312);
 		let hotkey_account_id1 = 2;
 		let coldkey_account_id1 = 668;
 		assert_ok!(ParatensorModule::register(<<Test as Config>::Origin>::signed(hotkey_account_id1), netuid, block_number, nonce1, work1, hotkey_account_id1, coldkey_account_id1));
 		let neuron_uid1 = ParatensorModule::get_neuron_for_net_and_hotkey(netuid, &hotkey_account_id1);
+		step_block( ParatensorModule::get_immunity_period( netuid ) );
+		ParatensorModule::set_prunning_score(netuid, neuron_uid, 2);
 		ParatensorModule::set_prunning_score(netuid, neuron_uid1, 3);
 		//
 		let (nonce2, work2): (u64, Vec<u8>) = ParatensorModule::create_work_for_block_number( netuid, block_number, 212312414);
@@ -293,5 +598,50 @@ fn test_registration_pruning() {
 		assert_eq!(ParatensorModule::if_incentive_is_set_for_neuron(netuid, neuron_uid), false);
 		assert_eq!(ParatensorModule::if_consensus_is_set_for_neuron(netuid, neuron_uid), false);
 		assert_eq!(ParatensorModule::if_dividend_is_set_for_neuron(netuid, neuron_uid), false);
+		// The bonds other neurons hold in the replaced uid are cleared at the next epoch.
+		assert_eq!(ParatensorModule::uids_to_prune(netuid), vec![neuron_uid]);
 	});
-}
\ No newline at end of file
+}
+#[test]
+fn test_migrate_registrations_per_block_to_map() {
+	new_test_ext().execute_with(|| {
+		add_network( 1, 0, 0 );
+		add_network( 2, 0, 0 );
+		register_ok_neuron( 1, 1, 667, 0 );
+		register_ok_neuron( 2, 2, 667, 300000 );
+		// Global values written under the StorageValue layout.
+		put_storage_value( b"ParatensorModule", b"MaxRegistrationsPerBlock", &[], 7u16 );
+		put_storage_value( b"ParatensorModule", b"RegistrationsThisBlock", &[], 5u16 );
+		StorageVersion::new( 8 ).put::<ParatensorModule>();
+
+		ParatensorModule::migrate_to_v9_registrations_per_block();
+		assert_eq!( ParatensorModule::get_max_registratations_per_block( 1 ), 7 );
+		assert_eq!( ParatensorModule::get_max_registratations_per_block( 2 ), 7 );
+		assert_eq!( get_storage_value::<u16>( b"ParatensorModule", b"MaxRegistrationsPerBlock", &[] ), None );
+		assert_eq!( get_storage_value::<u16>( b"ParatensorModule", b"RegistrationsThisBlock", &[] ), None );
+		assert_eq!( ParatensorModule::on_chain_storage_version(), 9 );
+	});
+}
+
+#[test]
+fn test_migrate_registration_intervals_to_map() {
+	new_test_ext().execute_with(|| {
+		add_network( 1, 0, 0 );
+		add_network( 2, 0, 0 );
+		register_ok_neuron( 1, 1, 667, 0 );
+		register_ok_neuron( 2, 2, 667, 300000 );
+		// Global values written under the StorageValue layout.
+		put_storage_value( b"ParatensorModule", b"AdjustmentInterval", &[], 17u16 );
+		put_storage_value( b"ParatensorModule", b"TargetRegistrationsPerInterval", &[], 3u16 );
+		StorageVersion::new( 10 ).put::<ParatensorModule>();
+
+		ParatensorModule::migrate_to_v11_registration_intervals();
+		assert_eq!( ParatensorModule::get_adjustment_interval( 1 ), 17 );
+		assert_eq!( ParatensorModule::get_adjustment_interval( 2 ), 17 );
+		assert_eq!( ParatensorModule::get_target_registrations_per_interval( 1 ), 3 );
+		assert_eq!( ParatensorModule::get_target_registrations_per_interval( 2 ), 3 );
+		assert_eq!( get_storage_value::<u16>( b"ParatensorModule", b"AdjustmentInterval", &[] ), None );
+		assert_eq!( get_storage_value::<u16>( b"ParatensorModule", b"TargetRegistrationsPerInterval", &[] ), None );
+		assert_eq!( ParatensorModule::on_chain_storage_version(), 11 );
+	});
+}
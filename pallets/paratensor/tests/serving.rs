@@ -0,0 +1,238 @@
+use pallet_paratensor::{Error};
+use frame_support::{assert_ok};
+use frame_system::Config;
+use crate::{mock::*};
+use frame_support::sp_runtime::DispatchError;
+use frame_support::dispatch::{GetDispatchInfo, DispatchInfo};
+use frame_support::weights::{DispatchClass, Pays};
+
+mod mock;
+
+/********************************************
+	serving::serve_axon() tests
+*********************************************/
+#[test]
+fn test_serve_axon_dispatch_info_ok() {
+	new_test_ext().execute_with(|| {
+		let call = Call::ParatensorModule(ParatensorCall::serve_axon{netuid: 1, version: 2, ip: 1676056785, port: 128, ip_type: 4, modality: 0});
+		assert_eq!(call.get_dispatch_info(), DispatchInfo {
+			weight: 0,
+			class: DispatchClass::Normal,
+			pays_fee: Pays::No
+		});
+	});
+}
+
+#[test]
+fn test_serve_axon_ok() {
+	new_test_ext().execute_with(|| {
+		let netuid: u16 = 1;
+		add_network( netuid, 0, 0 );
+		register_ok_neuron( netuid, 1, 667, 0 );
+		assert_ok!(ParatensorModule::serve_axon(<<Test as Config>::Origin>::signed(1), netuid, 2, 1676056785, 128, 4, 0));
+		let axon = ParatensorModule::get_axon_info( netuid, &1 ).unwrap();
+		assert_eq!( axon.version, 2 );
+		assert_eq!( axon.ip, 1676056785 );
+		assert_eq!( axon.port, 128 );
+		assert_eq!( axon.ip_type, 4 );
+		assert_eq!( axon.block, 0 );
+		// The axon is only stored for the network it was served on.
+		assert_eq!( ParatensorModule::get_axon_info( 2, &1 ), None );
+	});
+}
+
+#[test]
+fn test_serve_axon_ipv6_ok() {
+	new_test_ext().execute_with(|| {
+		let netuid: u16 = 1;
+		add_network( netuid, 0, 0 );
+		register_ok_neuron( netuid, 1, 667, 0 );
+		assert_ok!(ParatensorModule::serve_axon(<<Test as Config>::Origin>::signed(1), netuid, 2, u128::MAX, 128, 6, 0));
+		assert_eq!( ParatensorModule::get_axon_info( netuid, &1 ).unwrap().ip, u128::MAX );
+	});
+}
+
+#[test]
+fn test_serve_axon_not_signed() {
+	new_test_ext().execute_with(|| {
+		let result = ParatensorModule::serve_axon(<<Test as Config>::Origin>::none(), 1, 2, 1676056785, 128, 4, 0);
+		assert_eq!( result, Err(DispatchError::BadOrigin.into()) );
+	});
+}
+
+#[test]
+fn test_serve_axon_not_registered() {
+	new_test_ext().execute_with(|| {
+		add_network( 1, 0, 0 );
+		let result = ParatensorModule::serve_axon(<<Test as Config>::Origin>::signed(1), 1, 2, 1676056785, 128, 4, 0);
+		assert_eq!( result, Err(Error::<Test>::NotRegistered.into()) );
+	});
+}
+
+#[test]
+fn test_serve_axon_invalid_ip_type() {
+	new_test_ext().execute_with(|| {
+		let netuid: u16 = 1;
+		add_network( netuid, 0, 0 );
+		register_ok_neuron( netuid, 1, 667, 0 );
+		let result = ParatensorModule::serve_axon(<<Test as Config>::Origin>::signed(1), netuid, 2, 1676056785, 128, 5, 0);
+		assert_eq!( result, Err(Error::<Test>::InvalidIpType.into()) );
+	});
+}
+
+#[test]
+fn test_serve_axon_invalid_ipv4_address() {
+	new_test_ext().execute_with(|| {
+		let netuid: u16 = 1;
+		add_network( netuid, 0, 0 );
+		register_ok_neuron( netuid, 1, 667, 0 );
+		let result = ParatensorModule::serve_axon(<<Test as Config>::Origin>::signed(1), netuid, 2, u32::MAX as u128 + 1, 128, 4, 0);
+		assert_eq!( result, Err(Error::<Test>::InvalidIpAddress.into()) );
+		let result = ParatensorModule::serve_axon(<<Test as Config>::Origin>::signed(1), netuid, 2, 0, 128, 4, 0);
+		assert_eq!( result, Err(Error::<Test>::InvalidIpAddress.into()) );
+	});
+}
+
+#[test]
+fn test_serve_axon_rate_limit() {
+	new_test_ext().execute_with(|| {
+		let netuid: u16 = 1;
+		add_network( netuid, 0, 0 );
+		register_ok_neuron( netuid, 1, 667, 0 );
+		ParatensorModule::set_serving_rate_limit( 2 );
+		assert_ok!(ParatensorModule::serve_axon(<<Test as Config>::Origin>::signed(1), netuid, 2, 1676056785, 128, 4, 0));
+		step_block( 1 );
+		let result = ParatensorModule::serve_axon(<<Test as Config>::Origin>::signed(1), netuid, 2, 1676056785, 129, 4, 0);
+		assert_eq!( result, Err(Error::<Test>::ServingRateLimitExceeded.into()) );
+		step_block( 1 );
+		assert_ok!(ParatensorModule::serve_axon(<<Test as Config>::Origin>::signed(1), netuid, 2, 1676056785, 129, 4, 0));
+		assert_eq!( ParatensorModule::get_axon_info( netuid, &1 ).unwrap().port, 129 );
+	});
+}
+
+#[test]
+fn test_serve_axon_rate_limit_across_networks() {
+	new_test_ext().execute_with(|| {
+		add_network( 1, 0, 0 );
+		add_network( 2, 0, 0 );
+		register_ok_neuron( 1, 1, 667, 0 );
+		register_ok_neuron( 2, 1, 667, 300000 );
+		ParatensorModule::set_serving_rate_limit( 2 );
+		assert_ok!(ParatensorModule::serve_axon(<<Test as Config>::Origin>::signed(1), 1, 2, 1676056785, 128, 4, 0));
+		step_block( 1 );
+		// The rate limit is per hotkey, serving on another network does not reset it.
+		let result = ParatensorModule::serve_axon(<<Test as Config>::Origin>::signed(1), 2, 2, 1676056785, 128, 4, 0);
+		assert_eq!( result, Err(Error::<Test>::ServingRateLimitExceeded.into()) );
+		step_block( 1 );
+		assert_ok!(ParatensorModule::serve_axon(<<Test as Config>::Origin>::signed(1), 2, 2, 1676056785, 128, 4, 0));
+		assert_eq!( ParatensorModule::get_last_axon_block( &1 ), Some( 2 ) );
+	});
+}
+
+#[test]
+fn test_serve_axon_invalid_modality() {
+	new_test_ext().execute_with(|| {
+		let netuid: u16 = 1;
+		add_network( netuid, 0, 0 );
+		register_ok_neuron( netuid, 1, 667, 0 );
+		let result = ParatensorModule::serve_axon(<<Test as Config>::Origin>::signed(1), netuid, 2, 1676056785, 128, 4, 3);
+		assert_eq!( result, Err(Error::<Test>::InvalidModality.into()) );
+		assert_eq!( ParatensorModule::get_axon_info( netuid, &1 ), None );
+	});
+}
+
+#[test]
+fn test_is_valid_ip_address() {
+	new_test_ext().execute_with(|| {
+		assert_eq!( ParatensorModule::is_valid_ip_address( 4, u32::MAX as u128 ), true );
+		assert_eq!( ParatensorModule::is_valid_ip_address( 4, u32::MAX as u128 + 1 ), false );
+		assert_eq!( ParatensorModule::is_valid_ip_address( 6, u32::MAX as u128 + 1 ), true );
+		assert_eq!( ParatensorModule::is_valid_ip_address( 5, 1 ), false );
+		assert_eq!( ParatensorModule::is_valid_ip_address( 6, 0 ), false );
+	});
+}
+
+/********************************************
+	serving::serve_prometheus() tests
+*********************************************/
+#[test]
+fn test_serve_prometheus_dispatch_info_ok() {
+	new_test_ext().execute_with(|| {
+		let call = Call::ParatensorModule(ParatensorCall::serve_prometheus{netuid: 1, version: 2, ip: 1676056785, port: 128, ip_type: 4});
+		assert_eq!(call.get_dispatch_info(), DispatchInfo {
+			weight: 0,
+			class: DispatchClass::Normal,
+			pays_fee: Pays::No
+		});
+	});
+}
+
+#[test]
+fn test_serve_prometheus_ok() {
+	new_test_ext().execute_with(|| {
+		let netuid: u16 = 1;
+		add_network( netuid, 0, 0 );
+		register_ok_neuron( netuid, 1, 667, 0 );
+		assert_ok!(ParatensorModule::serve_prometheus(<<Test as Config>::Origin>::signed(1), netuid, 2, 1676056785, 128, 4));
+		let prometheus = ParatensorModule::get_prometheus_info( netuid, &1 ).unwrap();
+		assert_eq!( prometheus.version, 2 );
+		assert_eq!( prometheus.ip, 1676056785 );
+		assert_eq!( prometheus.port, 128 );
+		assert_eq!( prometheus.ip_type, 4 );
+		// Serving prometheus does not serve an axon.
+		assert_eq!( ParatensorModule::get_axon_info( netuid, &1 ), None );
+	});
+}
+
+#[test]
+fn test_serve_prometheus_not_registered() {
+	new_test_ext().execute_with(|| {
+		add_network( 1, 0, 0 );
+		let result = ParatensorModule::serve_prometheus(<<Test as Config>::Origin>::signed(1), 1, 2, 1676056785, 128, 4);
+		assert_eq!( result, Err(Error::<Test>::NotRegistered.into()) );
+	});
+}
+
+#[test]
+fn test_serve_prometheus_invalid_ip() {
+	new_test_ext().execute_with(|| {
+		let netuid: u16 = 1;
+		add_network( netuid, 0, 0 );
+		register_ok_neuron( netuid, 1, 667, 0 );
+		let result = ParatensorModule::serve_prometheus(<<Test as Config>::Origin>::signed(1), netuid, 2, 1676056785, 128, 7);
+		assert_eq!( result, Err(Error::<Test>::InvalidIpType.into()) );
+		let result = ParatensorModule::serve_prometheus(<<Test as Config>::Origin>::signed(1), netuid, 2, u32::MAX as u128 + 1, 128, 4);
+		assert_eq!( result, Err(Error::<Test>::InvalidIpAddress.into()) );
+	});
+}
+
+#[test]
+fn test_serve_prometheus_rate_limit() {
+	new_test_ext().execute_with(|| {
+		let netuid: u16 = 1;
+		add_network( netuid, 0, 0 );
+		register_ok_neuron( netuid, 1, 667, 0 );
+		ParatensorModule::set_serving_rate_limit( 2 );
+		assert_ok!(ParatensorModule::serve_prometheus(<<Test as Config>::Origin>::signed(1), netuid, 2, 1676056785, 128, 4));
+		let result = ParatensorModule::serve_prometheus(<<Test as Config>::Origin>::signed(1), netuid, 2, 1676056785, 129, 4);
+		assert_eq!( result, Err(Error::<Test>::ServingRateLimitExceeded.into()) );
+		// The axon has its own rate limit.
+		assert_ok!(ParatensorModule::serve_axon(<<Test as Config>::Origin>::signed(1), netuid, 2, 1676056785, 128, 4, 0));
+	});
+}
+
+#[test]
+fn test_serve_prometheus_rate_limit_across_networks() {
+	new_test_ext().execute_with(|| {
+		add_network( 1, 0, 0 );
+		add_network( 2, 0, 0 );
+		register_ok_neuron( 1, 1, 667, 0 );
+		register_ok_neuron( 2, 1, 667, 300000 );
+		ParatensorModule::set_serving_rate_limit( 2 );
+		assert_ok!(ParatensorModule::serve_prometheus(<<Test as Config>::Origin>::signed(1), 1, 2, 1676056785, 128, 4));
+		let result = ParatensorModule::serve_prometheus(<<Test as Config>::Origin>::signed(1), 2, 2, 1676056785, 128, 4);
+		assert_eq!( result, Err(Error::<Test>::ServingRateLimitExceeded.into()) );
+		step_block( 2 );
+		assert_ok!(ParatensorModule::serve_prometheus(<<Test as Config>::Origin>::signed(1), 2, 2, 1676056785, 128, 4));
+	});
+}
@@ -0,0 +1,458 @@
+use pallet_paratensor::{Error, UnbondingChunk};
+use frame_support::{assert_ok, traits::Get};
+use frame_system::Config;
+use crate::{mock::*};
+use frame_support::sp_runtime::DispatchError;
+use frame_support::traits::{GetStorageVersion, StorageVersion};
+
+mod mock;
+
+/********************************************
+	staking::become_delegate() tests
+*********************************************/
+#[test]
+fn test_become_delegate_ok() {
+	new_test_ext().execute_with(|| {
+		add_network( 1, 0, 0 );
+		register_ok_neuron( 1, 1, 667, 0 );
+		assert_eq!( ParatensorModule::hotkey_is_delegate( &1 ), false );
+		assert_ok!( ParatensorModule::become_delegate( <<Test as Config>::Origin>::signed(667), 1 ) );
+		assert_eq!( ParatensorModule::hotkey_is_delegate( &1 ), true );
+	});
+}
+
+#[test]
+fn test_become_delegate_not_signed() {
+	new_test_ext().execute_with(|| {
+		let result = ParatensorModule::become_delegate( <<Test as Config>::Origin>::none(), 1 );
+		assert_eq!( result, Err(DispatchError::BadOrigin.into()) );
+	});
+}
+
+#[test]
+fn test_become_delegate_not_registered() {
+	new_test_ext().execute_with(|| {
+		let result = ParatensorModule::become_delegate( <<Test as Config>::Origin>::signed(667), 1 );
+		assert_eq!( result, Err(Error::<Test>::NotRegistered.into()) );
+	});
+}
+
+#[test]
+fn test_become_delegate_not_owner() {
+	new_test_ext().execute_with(|| {
+		add_network( 1, 0, 0 );
+		register_ok_neuron( 1, 1, 667, 0 );
+		let result = ParatensorModule::become_delegate( <<Test as Config>::Origin>::signed(668), 1 );
+		assert_eq!( result, Err(Error::<Test>::NonAssociatedColdKey.into()) );
+	});
+}
+
+#[test]
+fn test_become_delegate_twice() {
+	new_test_ext().execute_with(|| {
+		add_network( 1, 0, 0 );
+		register_ok_neuron( 1, 1, 667, 0 );
+		assert_ok!( ParatensorModule::become_delegate( <<Test as Config>::Origin>::signed(667), 1 ) );
+		let result = ParatensorModule::become_delegate( <<Test as Config>::Origin>::signed(667), 1 );
+		assert_eq!( result, Err(Error::<Test>::AlreadyDelegate.into()) );
+	});
+}
+
+/********************************************
+	staking::add_stake() nominator tests
+*********************************************/
+#[test]
+fn test_nominate_requires_delegate() {
+	new_test_ext().execute_with(|| {
+		add_network( 1, 0, 0 );
+		register_ok_neuron( 1, 1, 667, 0 );
+		ParatensorModule::add_balance_to_coldkey_account( &700, 1000 );
+		let result = ParatensorModule::add_stake( <<Test as Config>::Origin>::signed(700), 1, 100 );
+		assert_eq!( result, Err(Error::<Test>::NonAssociatedColdKey.into()) );
+	});
+}
+
+#[test]
+fn test_nominate_stake_is_accounted_per_coldkey() {
+	new_test_ext().execute_with(|| {
+		add_network( 1, 0, 0 );
+		register_ok_neuron( 1, 1, 667, 0 );
+		assert_ok!( ParatensorModule::become_delegate( <<Test as Config>::Origin>::signed(667), 1 ) );
+		ParatensorModule::add_balance_to_coldkey_account( &667, 1000 );
+		ParatensorModule::add_balance_to_coldkey_account( &700, 1000 );
+		assert_ok!( ParatensorModule::add_stake( <<Test as Config>::Origin>::signed(667), 1, 100 ) );
+		assert_ok!( ParatensorModule::add_stake( <<Test as Config>::Origin>::signed(700), 1, 300 ) );
+		assert_eq!( ParatensorModule::get_stake_for_coldkey_and_hotkey( &667, &1 ), 100 );
+		assert_eq!( ParatensorModule::get_stake_for_coldkey_and_hotkey( &700, &1 ), 300 );
+		assert_eq!( ParatensorModule::get_stake_for_hotkey( &1 ), 400 );
+		assert_eq!( ParatensorModule::get_neuron_stake_for_subnetwork( 1, 0 ), 400 );
+		assert_eq!( ParatensorModule::get_total_stake(), 400 );
+
+		// Each coldkey can only remove its own stake.
+		let result = ParatensorModule::remove_stake( <<Test as Config>::Origin>::signed(667), 1, 101 );
+		assert_eq!( result, Err(Error::<Test>::NotEnoughStaketoWithdraw.into()) );
+		assert_ok!( ParatensorModule::remove_stake( <<Test as Config>::Origin>::signed(700), 1, 200 ) );
+		assert_eq!( ParatensorModule::get_stake_for_coldkey_and_hotkey( &700, &1 ), 100 );
+		assert_eq!( ParatensorModule::get_stake_for_hotkey( &1 ), 200 );
+		assert_eq!( ParatensorModule::get_neuron_stake_for_subnetwork( 1, 0 ), 200 );
+		assert_eq!( ParatensorModule::get_total_stake(), 200 );
+		assert_eq!( ParatensorModule::get_locked_balance( &700 ), 300 );
+		step_block( ParatensorModule::get_unbonding_period() as u16 );
+		assert_ok!( ParatensorModule::withdraw_unbonded( <<Test as Config>::Origin>::signed(700) ) );
+		assert_eq!( ParatensorModule::get_locked_balance( &700 ), 100 );
+		assert_eq!( ParatensorModule::get_coldkey_balance( &700 ), 1000 );
+	});
+}
+
+#[test]
+fn test_nominator_stake_returned_on_network_removal() {
+	new_test_ext().execute_with(|| {
+		add_network( 1, 0, 0 );
+		register_ok_neuron( 1, 1, 667, 0 );
+		assert_ok!( ParatensorModule::become_delegate( <<Test as Config>::Origin>::signed(667), 1 ) );
+		ParatensorModule::add_balance_to_coldkey_account( &700, 1000 );
+		assert_ok!( ParatensorModule::add_stake( <<Test as Config>::Origin>::signed(700), 1, 300 ) );
+		assert_ok!( ParatensorModule::sudo_remove_network( <<Test as Config>::Origin>::root(), 1 ) );
+		assert_eq!( ParatensorModule::get_coldkey_balance( &667 ), 0 );
+		assert_eq!( ParatensorModule::get_stake_for_coldkey_and_hotkey( &700, &1 ), 0 );
+		assert_eq!( ParatensorModule::get_total_stake(), 0 );
+		assert_eq!( ParatensorModule::hotkey_is_delegate( &1 ), false );
+
+		// The stake is unbonding and stays locked until the unbonding period has passed.
+		let unlock_block: u64 = ParatensorModule::get_current_block_as_u64() + ParatensorModule::get_unbonding_period();
+		assert_eq!( ParatensorModule::get_unbonding_chunks( &700 ), vec![ UnbondingChunk { hotkey: 1, amount: 300, unlock_block: unlock_block } ] );
+		assert_eq!( ParatensorModule::get_coldkey_balance( &700 ), 1000 );
+		assert_eq!( ParatensorModule::get_locked_balance( &700 ), 300 );
+		step_block( ParatensorModule::get_unbonding_period() as u16 - 1 );
+		let result = ParatensorModule::withdraw_unbonded( <<Test as Config>::Origin>::signed(700) );
+		assert_eq!( result, Err(Error::<Test>::NothingToWithdraw.into()) );
+		assert_eq!( ParatensorModule::get_locked_balance( &700 ), 300 );
+		step_block( 1 );
+		assert_ok!( ParatensorModule::withdraw_unbonded( <<Test as Config>::Origin>::signed(700) ) );
+		assert_eq!( ParatensorModule::get_locked_balance( &700 ), 0 );
+		assert_eq!( ParatensorModule::get_coldkey_balance( &700 ), 1000 );
+	});
+}
+
+#[test]
+fn test_network_removal_merges_into_full_unbonding_queue() {
+	new_test_ext().execute_with(|| {
+		add_network( 1, 0, 0 );
+		register_ok_neuron( 1, 1, 667, 0 );
+		ParatensorModule::add_balance_to_coldkey_account( &667, 1000 );
+		assert_ok!( ParatensorModule::add_stake( <<Test as Config>::Origin>::signed(667), 1, 300 ) );
+		let max_chunks: u32 = <Test as pallet_paratensor::Config>::MaxUnbondingChunks::get();
+		for _ in 0..max_chunks {
+			assert_ok!( ParatensorModule::remove_stake( <<Test as Config>::Origin>::signed(667), 1, 10 ) );
+		}
+		step_block( 1 );
+		assert_ok!( ParatensorModule::sudo_remove_network( <<Test as Config>::Origin>::root(), 1 ) );
+
+		// The remaining stake is merged into the last chunk instead of being released.
+		let chunks = ParatensorModule::get_unbonding_chunks( &667 );
+		assert_eq!( chunks.len() as u32, max_chunks );
+		assert_eq!( chunks.last().unwrap().amount, 10 + 300 - 10 * max_chunks as u64 );
+		assert_eq!( chunks.last().unwrap().unlock_block, ParatensorModule::get_current_block_as_u64() + ParatensorModule::get_unbonding_period() );
+		assert_eq!( ParatensorModule::get_locked_balance( &667 ), 300 );
+	});
+}
+
+#[test]
+fn test_migrate_to_coldkey_stake() {
+	new_test_ext().execute_with(|| {
+		// Stake held on the hotkey alone.
+		ParatensorModule::add_global_account( &1, &667 );
+		ParatensorModule::add_stake_for_hotkey( &1, 500 );
+		StorageVersion::new( 4 ).put::<ParatensorModule>();
+
+		ParatensorModule::migrate_to_v5_coldkey_stake();
+		assert_eq!( ParatensorModule::get_stake_for_coldkey_and_hotkey( &667, &1 ), 500 );
+		assert_eq!( ParatensorModule::on_chain_storage_version(), 5 );
+	});
+}
+
+/********************************************
+	staking::set_delegate_take() tests
+*********************************************/
+#[test]
+fn test_set_delegate_take_ok() {
+	new_test_ext().execute_with(|| {
+		add_network( 1, 0, 0 );
+		register_ok_neuron( 1, 1, 667, 0 );
+		assert_ok!( ParatensorModule::become_delegate( <<Test as Config>::Origin>::signed(667), 1 ) );
+		assert_eq!( ParatensorModule::get_delegate_take( &1 ), 11_796 );
+		assert_ok!( ParatensorModule::set_delegate_take( <<Test as Config>::Origin>::signed(667), 1, 13_107 ) );
+		assert_eq!( ParatensorModule::get_delegate_take( &1 ), 13_107 );
+	});
+}
+
+#[test]
+fn test_set_delegate_take_errors() {
+	new_test_ext().execute_with(|| {
+		add_network( 1, 0, 0 );
+		register_ok_neuron( 1, 1, 667, 0 );
+		let result = ParatensorModule::set_delegate_take( <<Test as Config>::Origin>::signed(667), 1, 100 );
+		assert_eq!( result, Err(Error::<Test>::NotADelegate.into()) );
+		assert_ok!( ParatensorModule::become_delegate( <<Test as Config>::Origin>::signed(667), 1 ) );
+		let result = ParatensorModule::set_delegate_take( <<Test as Config>::Origin>::signed(668), 1, 100 );
+		assert_eq!( result, Err(Error::<Test>::NonAssociatedColdKey.into()) );
+		let max_take: u16 = <Test as pallet_paratensor::Config>::MaximumDelegateTake::get();
+		let result = ParatensorModule::set_delegate_take( <<Test as Config>::Origin>::signed(667), 1, max_take + 1 );
+		assert_eq!( result, Err(Error::<Test>::DelegateTakeOutOfRange.into()) );
+	});
+}
+
+#[test]
+fn test_delegate_emission_split() {
+	new_test_ext().execute_with(|| {
+		add_network( 1, 0, 0 );
+		register_ok_neuron( 1, 1, 667, 0 );
+		assert_ok!( ParatensorModule::become_delegate( <<Test as Config>::Origin>::signed(667), 1 ) );
+		assert_ok!( ParatensorModule::set_delegate_take( <<Test as Config>::Origin>::signed(667), 1, 0 ) );
+		ParatensorModule::add_balance_to_coldkey_account( &700, 1000 );
+		ParatensorModule::add_balance_to_coldkey_account( &701, 1000 );
+		assert_ok!( ParatensorModule::add_stake( <<Test as Config>::Origin>::signed(700), 1, 300 ) );
+		assert_ok!( ParatensorModule::add_stake( <<Test as Config>::Origin>::signed(701), 1, 100 ) );
+
+		// Without a take the emission is split pro rata over the nominators.
+		assert_eq!( ParatensorModule::emit_inflation_through_hotkey_account( &1, 1000 ), 1000 );
+		assert_eq!( ParatensorModule::get_stake_for_coldkey_and_hotkey( &700, &1 ), 1050 );
+		assert_eq!( ParatensorModule::get_stake_for_coldkey_and_hotkey( &701, &1 ), 350 );
+
+		// A take of one fifth goes to the owner first.
+		assert_ok!( ParatensorModule::set_delegate_take( <<Test as Config>::Origin>::signed(667), 1, 13_107 ) );
+		ParatensorModule::emit_inflation_through_hotkey_account( &1, 1000 );
+		assert_eq!( ParatensorModule::get_stake_for_coldkey_and_hotkey( &667, &1 ), 200 );
+		assert_eq!( ParatensorModule::get_stake_for_coldkey_and_hotkey( &700, &1 ), 1650 );
+		assert_eq!( ParatensorModule::get_stake_for_coldkey_and_hotkey( &701, &1 ), 550 );
+		assert_eq!( ParatensorModule::get_stake_for_hotkey( &1 ), 2400 );
+		assert_eq!( ParatensorModule::get_total_stake(), 2400 );
+
+		// Pending rewards can be withdrawn.
+		assert_ok!( ParatensorModule::sudo_set_unbonding_period( <<Test as Config>::Origin>::root(), 0 ) );
+		assert_ok!( ParatensorModule::remove_stake( <<Test as Config>::Origin>::signed(701), 1, 550 ) );
+		assert_eq!( ParatensorModule::get_stake_for_coldkey_and_hotkey( &701, &1 ), 0 );
+		assert_eq!( ParatensorModule::get_stake_for_hotkey( &1 ), 1850 );
+		assert_eq!( ParatensorModule::get_locked_balance( &701 ), 550 );
+		assert_ok!( ParatensorModule::withdraw_unbonded( <<Test as Config>::Origin>::signed(701) ) );
+		assert_eq!( ParatensorModule::get_coldkey_balance( &701 ), 1450 );
+		assert_eq!( ParatensorModule::get_locked_balance( &701 ), 0 );
+	});
+}
+
+#[test]
+fn test_emission_to_non_delegate_goes_to_owner() {
+	new_test_ext().execute_with(|| {
+		add_network( 1, 0, 0 );
+		register_ok_neuron( 1, 1, 667, 0 );
+		assert_eq!( ParatensorModule::emit_inflation_through_hotkey_account( &1, 1000 ), 1000 );
+		assert_eq!( ParatensorModule::get_stake_for_coldkey_and_hotkey( &667, &1 ), 1000 );
+		assert_eq!( ParatensorModule::get_stake_for_hotkey( &1 ), 1000 );
+	});
+}
+
+/********************************************
+	unbonding::withdraw_unbonded() tests
+*********************************************/
+#[test]
+fn test_remove_stake_queues_unbonding_chunk() {
+	new_test_ext().execute_with(|| {
+		add_network( 1, 0, 0 );
+		register_ok_neuron( 1, 1, 667, 0 );
+		ParatensorModule::add_balance_to_coldkey_account( &667, 1000 );
+		assert_ok!( ParatensorModule::add_stake( <<Test as Config>::Origin>::signed(667), 1, 300 ) );
+		assert_ok!( ParatensorModule::remove_stake( <<Test as Config>::Origin>::signed(667), 1, 100 ) );
+		assert_eq!( ParatensorModule::get_stake_for_hotkey( &1 ), 200 );
+		assert_eq!( ParatensorModule::get_total_stake(), 200 );
+		assert_eq!( ParatensorModule::get_locked_balance( &667 ), 300 );
+		let unlock_block: u64 = ParatensorModule::get_current_block_as_u64() + ParatensorModule::get_unbonding_period();
+		assert_eq!( ParatensorModule::get_unbonding_chunks( &667 ), vec![ UnbondingChunk { hotkey: 1, amount: 100, unlock_block: unlock_block } ] );
+	});
+}
+
+#[test]
+fn test_withdraw_unbonded_before_unlock() {
+	new_test_ext().execute_with(|| {
+		add_network( 1, 0, 0 );
+		register_ok_neuron( 1, 1, 667, 0 );
+		let result = ParatensorModule::withdraw_unbonded( <<Test as Config>::Origin>::signed(667) );
+		assert_eq!( result, Err(Error::<Test>::NothingToWithdraw.into()) );
+		ParatensorModule::add_balance_to_coldkey_account( &667, 1000 );
+		assert_ok!( ParatensorModule::add_stake( <<Test as Config>::Origin>::signed(667), 1, 300 ) );
+		assert_ok!( ParatensorModule::remove_stake( <<Test as Config>::Origin>::signed(667), 1, 100 ) );
+		step_block( ParatensorModule::get_unbonding_period() as u16 - 1 );
+		let result = ParatensorModule::withdraw_unbonded( <<Test as Config>::Origin>::signed(667) );
+		assert_eq!( result, Err(Error::<Test>::NothingToWithdraw.into()) );
+		assert_eq!( ParatensorModule::get_locked_balance( &667 ), 300 );
+	});
+}
+
+#[test]
+fn test_withdraw_unbonded_releases_unlocked_chunks() {
+	new_test_ext().execute_with(|| {
+		add_network( 1, 0, 0 );
+		register_ok_neuron( 1, 1, 667, 0 );
+		ParatensorModule::add_balance_to_coldkey_account( &667, 1000 );
+		assert_ok!( ParatensorModule::add_stake( <<Test as Config>::Origin>::signed(667), 1, 300 ) );
+		assert_ok!( ParatensorModule::remove_stake( <<Test as Config>::Origin>::signed(667), 1, 100 ) );
+		step_block( 1 );
+		assert_ok!( ParatensorModule::remove_stake( <<Test as Config>::Origin>::signed(667), 1, 50 ) );
+
+		// Only the first chunk has unlocked.
+		step_block( ParatensorModule::get_unbonding_period() as u16 - 1 );
+		assert_ok!( ParatensorModule::withdraw_unbonded( <<Test as Config>::Origin>::signed(667) ) );
+		assert_eq!( ParatensorModule::get_locked_balance( &667 ), 200 );
+		assert_eq!( ParatensorModule::get_unbonding_chunks( &667 ).len(), 1 );
+
+		step_block( 1 );
+		assert_ok!( ParatensorModule::withdraw_unbonded( <<Test as Config>::Origin>::signed(667) ) );
+		assert_eq!( ParatensorModule::get_locked_balance( &667 ), 150 );
+		assert_eq!( ParatensorModule::get_unbonding_chunks( &667 ), vec![] );
+	});
+}
+
+#[test]
+fn test_remove_stake_zero() {
+	new_test_ext().execute_with(|| {
+		add_network( 1, 0, 0 );
+		register_ok_neuron( 1, 1, 667, 0 );
+		ParatensorModule::add_balance_to_coldkey_account( &667, 1000 );
+		assert_ok!( ParatensorModule::add_stake( <<Test as Config>::Origin>::signed(667), 1, 300 ) );
+		let result = ParatensorModule::remove_stake( <<Test as Config>::Origin>::signed(667), 1, 0 );
+		assert_eq!( result, Err(Error::<Test>::StakeToWithdrawIsZero.into()) );
+		assert_eq!( ParatensorModule::get_unbonding_chunks( &667 ), vec![] );
+	});
+}
+
+#[test]
+fn test_withdraw_unbonded_drops_zero_chunks() {
+	new_test_ext().execute_with(|| {
+		// A chunk of zero stake still takes up a slot in the queue.
+		ParatensorModule::add_unbonding_chunk( &667, &1, 0 );
+		step_block( ParatensorModule::get_unbonding_period() as u16 );
+		assert_ok!( ParatensorModule::withdraw_unbonded( <<Test as Config>::Origin>::signed(667) ) );
+		assert_eq!( ParatensorModule::get_unbonding_chunks( &667 ), vec![] );
+		let result = ParatensorModule::withdraw_unbonded( <<Test as Config>::Origin>::signed(667) );
+		assert_eq!( result, Err(Error::<Test>::NothingToWithdraw.into()) );
+	});
+}
+
+#[test]
+fn test_remove_stake_too_many_unbonding_chunks() {
+	new_test_ext().execute_with(|| {
+		add_network( 1, 0, 0 );
+		register_ok_neuron( 1, 1, 667, 0 );
+		ParatensorModule::add_balance_to_coldkey_account( &667, 1000 );
+		assert_ok!( ParatensorModule::add_stake( <<Test as Config>::Origin>::signed(667), 1, 300 ) );
+		let max_chunks: u32 = <Test as pallet_paratensor::Config>::MaxUnbondingChunks::get();
+		for _ in 0..max_chunks {
+			assert_ok!( ParatensorModule::remove_stake( <<Test as Config>::Origin>::signed(667), 1, 10 ) );
+		}
+		let result = ParatensorModule::remove_stake( <<Test as Config>::Origin>::signed(667), 1, 10 );
+		assert_eq!( result, Err(Error::<Test>::TooManyUnbondingChunks.into()) );
+
+		// Withdrawing frees up the queue.
+		step_block( ParatensorModule::get_unbonding_period() as u16 );
+		assert_ok!( ParatensorModule::withdraw_unbonded( <<Test as Config>::Origin>::signed(667) ) );
+		assert_ok!( ParatensorModule::remove_stake( <<Test as Config>::Origin>::signed(667), 1, 10 ) );
+	});
+}
+
+#[test]
+fn test_sudo_set_unbonding_period() {
+	new_test_ext().execute_with(|| {
+		let result = ParatensorModule::sudo_set_unbonding_period( <<Test as Config>::Origin>::signed(667), 5 );
+		assert_eq!( result, Err(DispatchError::BadOrigin.into()) );
+		let max_period: u64 = <Test as pallet_paratensor::Config>::MaximumUnbondingPeriod::get();
+		let result = ParatensorModule::sudo_set_unbonding_period( <<Test as Config>::Origin>::root(), max_period + 1 );
+		assert_eq!( result, Err(Error::<Test>::UnbondingPeriodOutOfRange.into()) );
+		assert_ok!( ParatensorModule::sudo_set_unbonding_period( <<Test as Config>::Origin>::root(), 5 ) );
+		assert_eq!( ParatensorModule::get_unbonding_period(), 5 );
+	});
+}
+
+/********************************************
+	staking stake lock tests
+*********************************************/
+#[test]
+fn test_add_stake_locks_balance_in_coldkey() {
+	new_test_ext().execute_with(|| {
+		add_network( 1, 0, 0 );
+		register_ok_neuron( 1, 1, 667, 0 );
+		ParatensorModule::add_balance_to_coldkey_account( &667, 1000 );
+		let issuance_before: u128 = Balances::total_issuance();
+		assert_ok!( ParatensorModule::add_stake( <<Test as Config>::Origin>::signed(667), 1, 600 ) );
+		assert_eq!( ParatensorModule::get_coldkey_balance( &667 ), 1000 );
+		assert_eq!( ParatensorModule::get_locked_balance( &667 ), 600 );
+		assert_eq!( Balances::total_issuance(), issuance_before );
+
+		// Locked stake can not be transferred or staked twice.
+		assert!( Balances::transfer( <<Test as Config>::Origin>::signed(667), 2, 500 ).is_err() );
+		assert_ok!( Balances::transfer( <<Test as Config>::Origin>::signed(667), 2, 400 ) );
+		let result = ParatensorModule::add_stake( <<Test as Config>::Origin>::signed(667), 1, 1 );
+		assert_eq!( result, Err(Error::<Test>::NotEnoughBalanceToStake.into()) );
+
+		// Unbonded stake is unlocked without minting.
+		assert_ok!( ParatensorModule::remove_stake( <<Test as Config>::Origin>::signed(667), 1, 600 ) );
+		step_block( ParatensorModule::get_unbonding_period() as u16 );
+		assert_ok!( ParatensorModule::withdraw_unbonded( <<Test as Config>::Origin>::signed(667) ) );
+		assert_eq!( ParatensorModule::get_locked_balance( &667 ), 0 );
+		assert_ok!( Balances::transfer( <<Test as Config>::Origin>::signed(667), 2, 600 ) );
+		assert_eq!( Balances::total_issuance(), issuance_before );
+	});
+}
+
+#[test]
+fn test_emission_is_minted_locked() {
+	new_test_ext().execute_with(|| {
+		add_network( 1, 0, 0 );
+		register_ok_neuron( 1, 1, 667, 0 );
+		let issuance_before: u128 = Balances::total_issuance();
+		ParatensorModule::emit_inflation_through_hotkey_account( &1, 1000 );
+		assert_eq!( ParatensorModule::get_coldkey_balance( &667 ), 1000 );
+		assert_eq!( ParatensorModule::get_locked_balance( &667 ), 1000 );
+		assert_eq!( Balances::total_issuance(), issuance_before + 1000 );
+	});
+}
+
+#[test]
+fn test_migrate_to_stake_locks() {
+	new_test_ext().execute_with(|| {
+		// Stake and unbonding chunks withdrawn from the coldkey.
+		ParatensorModule::add_global_account( &1, &667 );
+		ParatensorModule::increase_stake_on_coldkey_hotkey_account( &667, &1, 500 );
+		ParatensorModule::add_unbonding_chunk( &667, &1, 100 );
+		StorageVersion::new( 6 ).put::<ParatensorModule>();
+
+		ParatensorModule::migrate_to_v7_stake_locks();
+		assert_eq!( ParatensorModule::get_coldkey_balance( &667 ), 600 );
+		assert_eq!( ParatensorModule::get_locked_balance( &667 ), 600 );
+		assert_eq!( ParatensorModule::on_chain_storage_version(), 7 );
+	});
+}
+
+#[test]
+fn test_migrate_to_stake_locks_pending_and_unaccounted_stake() {
+	new_test_ext().execute_with(|| {
+		add_network( 1, 0, 0 );
+		register_ok_neuron( 1, 1, 667, 0 );
+		assert_ok!( ParatensorModule::become_delegate( <<Test as Config>::Origin>::signed(667), 1 ) );
+		assert_ok!( ParatensorModule::set_delegate_take( <<Test as Config>::Origin>::signed(667), 1, 0 ) );
+		// Nominated stake, a pending reward and stake not accounted to any coldkey, none of it in a coldkey account.
+		ParatensorModule::increase_stake_on_coldkey_hotkey_account( &700, &1, 300 );
+		ParatensorModule::emit_inflation_through_hotkey_account( &1, 900 );
+		ParatensorModule::add_stake_to_neuron_hotkey_account( &1, 200 );
+		ParatensorModule::add_unbonding_chunk( &700, &1, 100 );
+		StorageVersion::new( 6 ).put::<ParatensorModule>();
+
+		ParatensorModule::migrate_to_v7_stake_locks();
+		assert_eq!( ParatensorModule::get_coldkey_balance( &700 ), 1300 );
+		assert_eq!( ParatensorModule::get_locked_balance( &700 ), 1300 );
+		assert_eq!( ParatensorModule::get_stake_for_coldkey_and_hotkey( &700, &1 ), 1200 );
+		assert_eq!( ParatensorModule::get_coldkey_balance( &667 ), 200 );
+		assert_eq!( ParatensorModule::get_locked_balance( &667 ), 200 );
+		assert_eq!( ParatensorModule::get_stake_for_coldkey_and_hotkey( &667, &1 ), 200 );
+		assert_eq!( ParatensorModule::get_stake_for_hotkey( &1 ), 1400 );
+		assert_eq!( ParatensorModule::get_total_stake(), 1400 );
+	});
+}
@@ -0,0 +1,347 @@
+use pallet_paratensor::{Error, SubnetHyperparams};
+use frame_support::{assert_ok, traits::Get};
+use frame_system::Config;
+use crate::{mock::*};
+use frame_support::sp_runtime::DispatchError;
+
+mod mock;
+
+/********************************************
+	sudo_set_* hyperparameter tests
+*********************************************/
+#[test]
+fn test_sudo_set_registration_hyperparams() {
+	new_test_ext().execute_with(|| {
+		let netuid: u16 = 1;
+		add_network( netuid, 0, 0 );
+		assert_ok!( ParatensorModule::sudo_set_difficulty( <<Test as Config>::Origin>::root(), netuid, 20000 ) );
+		assert_ok!( ParatensorModule::sudo_set_adjustment_interval( <<Test as Config>::Origin>::root(), netuid, 50 ) );
+		assert_ok!( ParatensorModule::sudo_set_target_registrations_per_interval( <<Test as Config>::Origin>::root(), netuid, 5 ) );
+		assert_ok!( ParatensorModule::sudo_set_max_registrations_per_block( <<Test as Config>::Origin>::root(), netuid, 7 ) );
+		assert_ok!( ParatensorModule::sudo_set_immunity_period( <<Test as Config>::Origin>::root(), netuid, 11 ) );
+		assert_eq!( ParatensorModule::get_difficulty_as_u64( netuid ), 20000 );
+		assert_eq!( ParatensorModule::get_adjustment_interval( netuid ), 50 );
+		assert_eq!( ParatensorModule::get_target_registrations_per_interval( netuid ), 5 );
+		assert_eq!( ParatensorModule::get_max_registratations_per_block( netuid ), 7 );
+		assert_eq!( ParatensorModule::get_immunity_period( netuid ), 11 );
+	});
+}
+
+#[test]
+fn test_sudo_set_consensus_hyperparams() {
+	new_test_ext().execute_with(|| {
+		let netuid: u16 = 1;
+		add_network( netuid, 0, 0 );
+		assert_ok!( ParatensorModule::sudo_set_tempo( <<Test as Config>::Origin>::root(), netuid, 3 ) );
+		assert_ok!( ParatensorModule::sudo_set_rho( <<Test as Config>::Origin>::root(), netuid, 12 ) );
+		assert_ok!( ParatensorModule::sudo_set_kappa( <<Test as Config>::Origin>::root(), netuid, 13 ) );
+		assert_ok!( ParatensorModule::sudo_set_activity_cutoff( <<Test as Config>::Origin>::root(), netuid, 14 ) );
+		assert_ok!( ParatensorModule::sudo_set_min_allowed_weights( <<Test as Config>::Origin>::root(), netuid, 15 ) );
+		assert_ok!( ParatensorModule::sudo_set_max_allowed_max_min_ratio( <<Test as Config>::Origin>::root(), netuid, 16 ) );
+		assert_eq!( ParatensorModule::get_tempo( netuid ), 3 );
+		assert_eq!( ParatensorModule::get_rho( netuid ), 12 );
+		assert_eq!( ParatensorModule::get_kappa( netuid ), 13 );
+		assert_eq!( ParatensorModule::get_activity_cutoff( netuid ), 14 );
+		assert_eq!( ParatensorModule::get_min_allowed_weights( netuid ), 15 );
+		assert_eq!( ParatensorModule::get_max_allowed_max_min_ratio( netuid ), 16 );
+	});
+}
+
+#[test]
+fn test_sudo_set_epoch_hyperparams() {
+	new_test_ext().execute_with(|| {
+		let netuid: u16 = 1;
+		add_network( netuid, 0, 0 );
+		assert_ok!( ParatensorModule::sudo_set_bonds_moving_average( <<Test as Config>::Origin>::root(), netuid, 900_000 ) );
+		assert_ok!( ParatensorModule::sudo_set_trust_threshold( <<Test as Config>::Origin>::root(), netuid, 1000 ) );
+		assert_eq!( ParatensorModule::get_bonds_moving_average( netuid ), 900_000 );
+		assert_eq!( ParatensorModule::get_trust_threshold( netuid ), 1000 );
+		assert_ok!( ParatensorModule::sudo_set_max_allowed_validators( <<Test as Config>::Origin>::root(), netuid, 9 ) );
+		assert_eq!( ParatensorModule::get_max_allowed_validators( netuid ), 9 );
+		let result = ParatensorModule::sudo_set_max_allowed_validators( <<Test as Config>::Origin>::root(), netuid, 0 );
+		assert_eq!( result, Err(Error::<Test>::MaxAllowedValidatorsOutOfRange.into()) );
+		let result = ParatensorModule::sudo_set_bonds_moving_average( <<Test as Config>::Origin>::root(), netuid, 1_000_001 );
+		assert_eq!( result, Err(Error::<Test>::BondsMovingAverageOutOfRange.into()) );
+		let result = ParatensorModule::sudo_set_trust_threshold( <<Test as Config>::Origin>::root(), 2, 1000 );
+		assert_eq!( result, Err(Error::<Test>::NetworkDoesNotExist.into()) );
+	});
+}
+
+#[test]
+fn test_sudo_set_validator_hyperparams() {
+	new_test_ext().execute_with(|| {
+		let netuid: u16 = 1;
+		add_network( netuid, 0, 0 );
+		assert_ok!( ParatensorModule::sudo_set_validator_batch_size( <<Test as Config>::Origin>::root(), netuid, 21 ) );
+		assert_ok!( ParatensorModule::sudo_set_validator_sequence_length( <<Test as Config>::Origin>::root(), netuid, 22 ) );
+		assert_ok!( ParatensorModule::sudo_set_validator_epoch_length( <<Test as Config>::Origin>::root(), netuid, 23 ) );
+		assert_ok!( ParatensorModule::sudo_set_validator_epochs_per_reset( <<Test as Config>::Origin>::root(), netuid, 24 ) );
+		assert_eq!( ParatensorModule::get_validator_batch_size( netuid ), 21 );
+		assert_eq!( ParatensorModule::get_validator_sequence_length( netuid ), 22 );
+		assert_eq!( ParatensorModule::get_validator_epoch_length( netuid ), 23 );
+		assert_eq!( ParatensorModule::get_validator_epochs_per_reset( netuid ), 24 );
+	});
+}
+
+#[test]
+fn test_sudo_set_pruning_hyperparams() {
+	new_test_ext().execute_with(|| {
+		let netuid: u16 = 1;
+		add_network( netuid, 0, 0 );
+		assert_ok!( ParatensorModule::sudo_set_incentive_pruning_denominator( <<Test as Config>::Origin>::root(), netuid, 31 ) );
+		assert_ok!( ParatensorModule::sudo_set_stake_pruning_denominator( <<Test as Config>::Origin>::root(), netuid, 32 ) );
+		assert_ok!( ParatensorModule::sudo_set_stake_pruning_min( <<Test as Config>::Origin>::root(), netuid, 33 ) );
+		assert_eq!( ParatensorModule::get_incentive_pruning_denominator( netuid ), 31 );
+		assert_eq!( ParatensorModule::get_stake_pruning_denominator( netuid ), 32 );
+		assert_eq!( ParatensorModule::get_stake_pruning_min( netuid ), 33 );
+	});
+}
+
+#[test]
+fn test_sudo_set_hyperparams_are_per_network() {
+	new_test_ext().execute_with(|| {
+		add_network( 1, 0, 0 );
+		add_network( 2, 0, 0 );
+		assert_ok!( ParatensorModule::sudo_set_kappa( <<Test as Config>::Origin>::root(), 1, 100 ) );
+		assert_eq!( ParatensorModule::get_kappa( 1 ), 100 );
+		assert_eq!( ParatensorModule::get_kappa( 2 ), 32_767 );
+	});
+}
+
+#[test]
+fn test_sudo_set_hyperparam_not_root() {
+	new_test_ext().execute_with(|| {
+		add_network( 1, 0, 0 );
+		let result = ParatensorModule::sudo_set_rho( <<Test as Config>::Origin>::signed(0), 1, 12 );
+		assert_eq!( result, Err(DispatchError::BadOrigin.into()) );
+		assert_eq!( ParatensorModule::get_rho( 1 ), 10 );
+	});
+}
+
+#[test]
+fn test_sudo_set_hyperparam_network_does_not_exist() {
+	new_test_ext().execute_with(|| {
+		let result = ParatensorModule::sudo_set_immunity_period( <<Test as Config>::Origin>::root(), 1, 12 );
+		assert_eq!( result, Err(Error::<Test>::NetworkDoesNotExist.into()) );
+	});
+}
+
+#[test]
+fn test_difficulty_adjustment_interval_is_per_network() {
+	new_test_ext().execute_with(|| {
+		add_network( 1, 0, 0 );
+		add_network( 2, 0, 0 );
+		register_ok_neuron( 1, 1, 1, 3942084 );
+		register_ok_neuron( 2, 2, 2, 11231312312 );
+		assert_ok!( ParatensorModule::sudo_set_adjustment_interval( <<Test as Config>::Origin>::root(), 2, 10 ) );
+		assert_ok!( ParatensorModule::sudo_set_difficulty( <<Test as Config>::Origin>::root(), 2, 20000 ) );
+
+		// Only network 2 has reached the end of its interval.
+		step_block( 10 );
+		assert_eq!( ParatensorModule::get_difficulty_as_u64( 1 ), 10000 );
+		assert_eq!( ParatensorModule::get_difficulty_as_u64( 2 ), 15000 );
+		assert_eq!( ParatensorModule::get_registrations_this_interval( 1 ), 1 );
+		assert_eq!( ParatensorModule::get_registrations_this_interval( 2 ), 0 );
+	});
+}
+
+/********************************************
+	hyperparameter bounds tests
+*********************************************/
+#[test]
+fn test_sudo_set_kappa_out_of_range() {
+	new_test_ext().execute_with(|| {
+		add_network( 1, 0, 0 );
+		let result = ParatensorModule::sudo_set_kappa( <<Test as Config>::Origin>::root(), 1, 0 );
+		assert_eq!( result, Err(Error::<Test>::KappaOutOfRange.into()) );
+		assert_eq!( ParatensorModule::get_kappa( 1 ), 32_767 );
+	});
+}
+
+#[test]
+fn test_sudo_set_rho_out_of_range() {
+	new_test_ext().execute_with(|| {
+		add_network( 1, 0, 0 );
+		let max_rho: u16 = <Test as pallet_paratensor::Config>::MaximumRho::get();
+		assert_ok!( ParatensorModule::sudo_set_rho( <<Test as Config>::Origin>::root(), 1, max_rho ) );
+		let result = ParatensorModule::sudo_set_rho( <<Test as Config>::Origin>::root(), 1, max_rho + 1 );
+		assert_eq!( result, Err(Error::<Test>::RhoOutOfRange.into()) );
+		assert_eq!( ParatensorModule::get_rho( 1 ), max_rho );
+	});
+}
+
+#[test]
+fn test_sudo_set_tempo_out_of_range() {
+	new_test_ext().execute_with(|| {
+		add_network( 1, 0, 0 );
+		let max_tempo: u16 = <Test as pallet_paratensor::Config>::MaximumTempo::get();
+		let result = ParatensorModule::sudo_set_tempo( <<Test as Config>::Origin>::root(), 1, max_tempo + 1 );
+		assert_eq!( result, Err(Error::<Test>::TempoOutOfRange.into()) );
+		let result = ParatensorModule::sudo_add_network( <<Test as Config>::Origin>::root(), 2, max_tempo + 1, 0 );
+		assert_eq!( result, Err(Error::<Test>::TempoOutOfRange.into()) );
+		assert_eq!( ParatensorModule::if_subnet_exist( 2 ), false );
+	});
+}
+
+#[test]
+fn test_sudo_set_max_allowed_uids_out_of_range() {
+	new_test_ext().execute_with(|| {
+		add_network( 1, 0, 0 );
+		let max_allowed_uids: u16 = <Test as pallet_paratensor::Config>::MaximumMaxAllowedUids::get();
+		let result = ParatensorModule::sudo_set_max_allowed_uids( <<Test as Config>::Origin>::root(), 1, max_allowed_uids + 1 );
+		assert_eq!( result, Err(Error::<Test>::MaxAllowedUidsOutOfRange.into()) );
+		let result = ParatensorModule::sudo_set_max_allowed_uids( <<Test as Config>::Origin>::root(), 1, 0 );
+		assert_eq!( result, Err(Error::<Test>::MaxAllowedUidsOutOfRange.into()) );
+	});
+}
+
+#[test]
+fn test_sudo_set_max_allowed_uids_below_current_uids() {
+	new_test_ext().execute_with(|| {
+		add_network( 1, 0, 0 );
+		register_ok_neuron( 1, 1, 667, 0 );
+		register_ok_neuron( 1, 2, 667, 300000 );
+		let result = ParatensorModule::sudo_set_max_allowed_uids( <<Test as Config>::Origin>::root(), 1, 1 );
+		assert_eq!( result, Err(Error::<Test>::MaxAllowedUidsLessThanCurrentUids.into()) );
+		assert_eq!( ParatensorModule::get_max_allowed_uids( 1 ), 2 );
+		assert_ok!( ParatensorModule::sudo_set_max_allowed_uids( <<Test as Config>::Origin>::root(), 1, 2 ) );
+	});
+}
+
+#[test]
+fn test_sudo_set_blocks_per_step_out_of_range() {
+	new_test_ext().execute_with(|| {
+		let result = ParatensorModule::sudo_set_blocks_per_step( <<Test as Config>::Origin>::root(), 0 );
+		assert_eq!( result, Err(Error::<Test>::BlocksPerStepOutOfRange.into()) );
+		assert_ok!( ParatensorModule::sudo_set_blocks_per_step( <<Test as Config>::Origin>::root(), 5 ) );
+		assert_eq!( ParatensorModule::get_blocks_per_step(), 5 );
+	});
+}
+
+#[test]
+fn test_sudo_set_difficulty_out_of_range() {
+	new_test_ext().execute_with(|| {
+		add_network( 1, 0, 0 );
+		let min_difficulty: u64 = <Test as pallet_paratensor::Config>::MinimumDifficulty::get();
+		let result = ParatensorModule::sudo_set_difficulty( <<Test as Config>::Origin>::root(), 1, min_difficulty - 1 );
+		assert_eq!( result, Err(Error::<Test>::DifficultyOutOfRange.into()) );
+	});
+}
+
+#[test]
+fn test_get_hyperparameter_ranges() {
+	new_test_ext().execute_with(|| {
+		let ranges = ParatensorModule::get_hyperparameter_ranges();
+		assert_eq!( ranges.kappa, ( 1, u16::MAX ) );
+		assert_eq!( ranges.rho, ( 1, 20 ) );
+		assert_eq!( ranges.tempo, ( 0, 100 ) );
+		assert_eq!( ranges.max_allowed_uids, ( 1, 100 ) );
+		assert_eq!( ranges.blocks_per_step, ( 1, 100 ) );
+		assert_eq!( ranges.unbonding_period, ( 0, 1000 ) );
+		assert_eq!( ranges.difficulty, ( 10000, u64::MAX/4 ) );
+	});
+}
+
+/********************************************
+	sudo_set_hyperparams() tests
+*********************************************/
+fn test_hyperparams() -> SubnetHyperparams {
+	SubnetHyperparams {
+		tempo: 3,
+		difficulty: 20000,
+		adjustment_interval: 50,
+		target_registrations_per_interval: 5,
+		max_registrations_per_block: 7,
+		activity_cutoff: 14,
+		rho: 12,
+		kappa: 13,
+		bonds_moving_average: 900_000,
+		trust_threshold: 1000,
+		max_allowed_validators: 9,
+		max_allowed_uids: 10,
+		min_allowed_weights: 15,
+		max_allowed_max_min_ratio: 16,
+		validator_batch_size: 21,
+		validator_sequence_length: 22,
+		validator_epoch_length: 23,
+		validator_epochs_per_reset: 24,
+		incentive_pruning_denominator: 31,
+		stake_pruning_denominator: 32,
+		stake_pruning_min: 33,
+		immunity_period: 11,
+	}
+}
+
+#[test]
+fn test_get_subnet_hyperparams_defaults() {
+	new_test_ext().execute_with(|| {
+		assert_eq!( ParatensorModule::get_subnet_hyperparams( 1 ), None );
+		add_network( 1, 5, 0 );
+		let params = ParatensorModule::get_subnet_hyperparams( 1 ).unwrap();
+		assert_eq!( params.tempo, 5 );
+		assert_eq!( params.difficulty, 10000 );
+		assert_eq!( params.rho, 10 );
+		assert_eq!( params.kappa, 32_767 );
+		assert_eq!( params.max_allowed_uids, 2 );
+		assert_eq!( params.immunity_period, 2 );
+	});
+}
+
+#[test]
+fn test_sudo_set_hyperparams_ok() {
+	new_test_ext().execute_with(|| {
+		add_network( 1, 0, 0 );
+		add_network( 2, 0, 0 );
+		assert_ok!( ParatensorModule::sudo_set_hyperparams( <<Test as Config>::Origin>::root(), 1, test_hyperparams() ) );
+		assert_eq!( ParatensorModule::get_subnet_hyperparams( 1 ), Some( test_hyperparams() ) );
+		assert_eq!( ParatensorModule::get_rho( 1 ), 12 );
+		assert_eq!( ParatensorModule::get_validator_epoch_length( 1 ), 23 );
+		// Other networks keep their own hyperparameters.
+		assert_eq!( ParatensorModule::get_rho( 2 ), 10 );
+	});
+}
+
+#[test]
+fn test_sudo_set_hyperparams_is_atomic() {
+	new_test_ext().execute_with(|| {
+		add_network( 1, 0, 0 );
+		let before = ParatensorModule::get_subnet_hyperparams( 1 ).unwrap();
+		let mut params = test_hyperparams();
+		params.kappa = 0;
+		let result = ParatensorModule::sudo_set_hyperparams( <<Test as Config>::Origin>::root(), 1, params );
+		assert_eq!( result, Err(Error::<Test>::KappaOutOfRange.into()) );
+		assert_eq!( ParatensorModule::get_subnet_hyperparams( 1 ), Some( before ) );
+	});
+}
+
+#[test]
+fn test_sudo_set_hyperparams_max_allowed_uids_below_current_uids() {
+	new_test_ext().execute_with(|| {
+		add_network( 1, 0, 0 );
+		register_ok_neuron( 1, 1, 667, 0 );
+		register_ok_neuron( 1, 2, 667, 300000 );
+		let before = ParatensorModule::get_subnet_hyperparams( 1 ).unwrap();
+		let mut params = test_hyperparams();
+		params.max_allowed_uids = 1;
+		let result = ParatensorModule::sudo_set_hyperparams( <<Test as Config>::Origin>::root(), 1, params );
+		assert_eq!( result, Err(Error::<Test>::MaxAllowedUidsLessThanCurrentUids.into()) );
+		assert_eq!( ParatensorModule::get_subnet_hyperparams( 1 ), Some( before ) );
+	});
+}
+
+#[test]
+fn test_sudo_set_hyperparams_network_does_not_exist() {
+	new_test_ext().execute_with(|| {
+		let result = ParatensorModule::sudo_set_hyperparams( <<Test as Config>::Origin>::root(), 1, test_hyperparams() );
+		assert_eq!( result, Err(Error::<Test>::NetworkDoesNotExist.into()) );
+	});
+}
+
+#[test]
+fn test_sudo_set_hyperparams_not_root() {
+	new_test_ext().execute_with(|| {
+		add_network( 1, 0, 0 );
+		let result = ParatensorModule::sudo_set_hyperparams( <<Test as Config>::Origin>::signed(0), 1, test_hyperparams() );
+		assert_eq!( result, Err(DispatchError::BadOrigin.into()) );
+	});
+}
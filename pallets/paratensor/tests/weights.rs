@@ -0,0 +1,141 @@
+use pallet_paratensor::{Error};
+use frame_support::{assert_ok};
+use frame_system::Config;
+use crate::{mock::*};
+use frame_support::sp_runtime::DispatchError;
+use frame_support::dispatch::{GetDispatchInfo, DispatchInfo};
+use frame_support::weights::{DispatchClass, Pays};
+
+mod mock;
+
+/********************************************
+	weights::set_weights() tests
+*********************************************/
+#[test]
+fn test_set_weights_dispatch_info_ok() {
+	new_test_ext().execute_with(|| {
+		let netuid: u16 = 1;
+		let dests = vec![1, 1];
+		let weights = vec![1, 1];
+		let call = Call::ParatensorModule(ParatensorCall::set_weights{netuid, dests, weights});
+		assert_eq!(call.get_dispatch_info(), DispatchInfo {
+			weight: 0,
+			class: DispatchClass::Normal,
+			pays_fee: Pays::No
+		});
+	});
+}
+
+#[test]
+fn test_set_weights_ok() {
+	new_test_ext().execute_with(|| {
+		add_network( 1, 0, 0 );
+		let netuid: u16 = 1;
+		register_ok_neuron( netuid, 1, 667, 0 );
+		register_ok_neuron( netuid, 2, 668, 300000 );
+		let neuron_uid: u16 = ParatensorModule::get_neuron_for_net_and_hotkey( netuid, &1 );
+		assert_ok!(ParatensorModule::set_weights(<<Test as Config>::Origin>::signed(1), netuid, vec![0, 1], vec![1, 1]));
+		assert_eq!(ParatensorModule::get_weights_for_neuron(netuid, neuron_uid), vec![(0, u16::MAX / 2), (1, u16::MAX / 2)]);
+	});
+}
+
+#[test]
+fn test_set_weights_records_last_update() {
+	new_test_ext().execute_with(|| {
+		add_network( 1, 0, 0 );
+		let netuid: u16 = 1;
+		register_ok_neuron( netuid, 1, 667, 0 );
+		assert_eq!(ParatensorModule::get_last_update_for_uid(netuid, 0), 0);
+		step_block( 5 );
+		assert_ok!(ParatensorModule::set_weights(<<Test as Config>::Origin>::signed(1), netuid, vec![0], vec![1]));
+		assert_eq!(ParatensorModule::get_last_update_for_uid(netuid, 0), 5);
+	});
+}
+
+#[test]
+fn test_set_weights_is_root_error() {
+	new_test_ext().execute_with(|| {
+		let result = ParatensorModule::set_weights(<<Test as Config>::Origin>::root(), 1, vec![0], vec![1]);
+		assert_eq!(result, Err(DispatchError::BadOrigin.into()));
+	});
+}
+
+#[test]
+fn test_set_weights_not_registered() {
+	new_test_ext().execute_with(|| {
+		add_network( 1, 0, 0 );
+		let result = ParatensorModule::set_weights(<<Test as Config>::Origin>::signed(1), 1, vec![0], vec![1]);
+		assert_eq!(result, Err(Error::<Test>::NotRegistered.into()));
+	});
+}
+
+#[test]
+fn test_set_weights_vec_not_equal_size() {
+	new_test_ext().execute_with(|| {
+		add_network( 1, 0, 0 );
+		let netuid: u16 = 1;
+		register_ok_neuron( netuid, 1, 667, 0 );
+		let result = ParatensorModule::set_weights(<<Test as Config>::Origin>::signed(1), netuid, vec![0], vec![1, 2]);
+		assert_eq!(result, Err(Error::<Test>::WeightVecNotEqualSize.into()));
+	});
+}
+
+#[test]
+fn test_set_weights_duplicate_uids() {
+	new_test_ext().execute_with(|| {
+		add_network( 1, 0, 0 );
+		let netuid: u16 = 1;
+		register_ok_neuron( netuid, 1, 667, 0 );
+		let result = ParatensorModule::set_weights(<<Test as Config>::Origin>::signed(1), netuid, vec![0, 0], vec![1, 2]);
+		assert_eq!(result, Err(Error::<Test>::DuplicateUids.into()));
+	});
+}
+
+#[test]
+fn test_set_weights_invalid_uid() {
+	new_test_ext().execute_with(|| {
+		add_network( 1, 0, 0 );
+		let netuid: u16 = 1;
+		register_ok_neuron( netuid, 1, 667, 0 );
+		let result = ParatensorModule::set_weights(<<Test as Config>::Origin>::signed(1), netuid, vec![0, 9], vec![1, 2]);
+		assert_eq!(result, Err(Error::<Test>::InvalidUid.into()));
+	});
+}
+
+#[test]
+fn test_set_weights_not_enough_weights() {
+	new_test_ext().execute_with(|| {
+		add_network( 1, 0, 0 );
+		let netuid: u16 = 1;
+		register_ok_neuron( netuid, 1, 667, 0 );
+		register_ok_neuron( netuid, 2, 668, 300000 );
+		ParatensorModule::set_min_allowed_weights( netuid, 2 );
+		let result = ParatensorModule::set_weights(<<Test as Config>::Origin>::signed(1), netuid, vec![1], vec![1]);
+		assert_eq!(result, Err(Error::<Test>::NotSettingEnoughWeights.into()));
+		// A single self weight is always allowed.
+		assert_ok!(ParatensorModule::set_weights(<<Test as Config>::Origin>::signed(1), netuid, vec![0], vec![1]));
+	});
+}
+
+#[test]
+fn test_set_weights_max_min_ratio_exceeded() {
+	new_test_ext().execute_with(|| {
+		add_network( 1, 0, 0 );
+		let netuid: u16 = 1;
+		register_ok_neuron( netuid, 1, 667, 0 );
+		register_ok_neuron( netuid, 2, 668, 300000 );
+		ParatensorModule::set_max_allowed_max_min_ratio( netuid, 2 );
+		let result = ParatensorModule::set_weights(<<Test as Config>::Origin>::signed(1), netuid, vec![0, 1], vec![1, 10]);
+		assert_eq!(result, Err(Error::<Test>::MaxAllowedMaxMinRatioExceeded.into()));
+		assert_ok!(ParatensorModule::set_weights(<<Test as Config>::Origin>::signed(1), netuid, vec![0, 1], vec![1, 2]));
+	});
+}
+
+#[test]
+fn test_normalize_weights() {
+	new_test_ext().execute_with(|| {
+		assert_eq!(ParatensorModule::normalize_weights(vec![0, 0]), vec![0, 0]);
+		assert_eq!(ParatensorModule::normalize_weights(vec![1]), vec![u16::MAX]);
+		assert_eq!(ParatensorModule::normalize_weights(vec![1, 1]), vec![u16::MAX / 2, u16::MAX / 2]);
+	});
+}
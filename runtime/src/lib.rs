@@ -463,7 +463,13 @@ impl pallet_collator_selection::Config for Runtime {
 // Configure the pallet paratensor.
 parameter_types! {
 	pub const ParatensorInitialRho: u16 = 10;
-	pub const ParatensorInitialKappa: u16 = 2;
+	pub const ParatensorInitialKappa: u16 = 32_767;
+	pub const ParatensorInitialBondsMovingAverage: u64 = 100_000;
+	pub const ParatensorInitialTrustThreshold: u16 = 655;
+	pub const ParatensorInitialMaxAllowedValidators: u16 = 128;
+	pub const ParatensorInitialDelegateTake: u16 = 11_796;
+	pub const ParatensorInitialUnbondingPeriod: u64 = 7200;
+	pub const ParatensorMaxUnbondingChunks: u32 = 32;
 	pub const ParatensorInitialMaxAllowedUids: u16 = 2000;
 	pub const ParatensorInitialIssuance: u64 = 0;
 	pub const ParatensorInitialGlobalN : u16 = 0;
@@ -476,6 +482,8 @@ parameter_types! {
 	pub const ParatensorInitialBlocksPerStep: u64 = 100;
 	pub const ParatensorInitialTempo: u16 = 0;
 	pub const ParatensorInitialDifficulty: u64 = 10000000;
+	pub const ParatensorMinimumDifficulty: u64 = 1;
+	pub const ParatensorMaximumDifficulty: u64 = u64::MAX / 4;
 	pub const ParatensorInitialAdjustmentInterval: u16 = 100;
 	pub const ParatensorInitialTargetRegistrationsPerInterval: u16 = 2;
 	pub const ParatensorInitialIncentivePruningDenominator: u16 = 1;
@@ -485,6 +493,55 @@ parameter_types! {
 	pub const ParatensorInitialMaxRegistrationsPerBlock: u16 = 2;
 	pub const ParatensorInitialStakePruningMin: u16 = 0;
 	pub const ParatensorInitialPrunningScore : u16 = u16::MAX;
+	pub const ParatensorInitialServingRateLimit: u64 = 50;
+	pub const ParatensorMinimumBlocksPerStep: u64 = 1;
+	pub const ParatensorMaximumBlocksPerStep: u64 = 10000;
+	pub const ParatensorMinimumTempo: u16 = 0;
+	pub const ParatensorMaximumTempo: u16 = 1000;
+	pub const ParatensorMinimumAdjustmentInterval: u16 = 1;
+	pub const ParatensorMaximumAdjustmentInterval: u16 = 10000;
+	pub const ParatensorMinimumTargetRegistrationsPerInterval: u16 = 1;
+	pub const ParatensorMaximumTargetRegistrationsPerInterval: u16 = 1000;
+	pub const ParatensorMinimumMaxRegistrationsPerBlock: u16 = 1;
+	pub const ParatensorMaximumMaxRegistrationsPerBlock: u16 = 100;
+	pub const ParatensorMinimumActivityCutoff: u16 = 1;
+	pub const ParatensorMaximumActivityCutoff: u16 = u16::MAX;
+	pub const ParatensorMinimumRho: u16 = 1;
+	pub const ParatensorMaximumRho: u16 = 20;
+	pub const ParatensorMinimumKappa: u16 = 1;
+	pub const ParatensorMaximumKappa: u16 = u16::MAX;
+	pub const ParatensorMinimumMaxAllowedUids: u16 = 1;
+	pub const ParatensorMaximumMaxAllowedUids: u16 = 4096;
+	pub const ParatensorMinimumMinAllowedWeights: u16 = 0;
+	pub const ParatensorMaximumMinAllowedWeights: u16 = 4096;
+	pub const ParatensorMinimumMaxAllowedMaxMinRatio: u16 = 0;
+	pub const ParatensorMaximumMaxAllowedMaxMinRatio: u16 = u16::MAX;
+	pub const ParatensorMinimumValidatorBatchSize: u16 = 1;
+	pub const ParatensorMaximumValidatorBatchSize: u16 = 1024;
+	pub const ParatensorMinimumValidatorSequenceLength: u16 = 1;
+	pub const ParatensorMaximumValidatorSequenceLength: u16 = 4096;
+	pub const ParatensorMinimumValidatorEpochLength: u16 = 1;
+	pub const ParatensorMaximumValidatorEpochLength: u16 = u16::MAX;
+	pub const ParatensorMinimumValidatorEpochsPerReset: u16 = 1;
+	pub const ParatensorMaximumValidatorEpochsPerReset: u16 = u16::MAX;
+	pub const ParatensorMinimumIncentivePruningDenominator: u16 = 0;
+	pub const ParatensorMaximumIncentivePruningDenominator: u16 = u16::MAX;
+	pub const ParatensorMinimumStakePruningDenominator: u16 = 0;
+	pub const ParatensorMaximumStakePruningDenominator: u16 = u16::MAX;
+	pub const ParatensorMinimumStakePruningMin: u16 = 0;
+	pub const ParatensorMaximumStakePruningMin: u16 = u16::MAX;
+	pub const ParatensorMinimumImmunityPeriod: u16 = 0;
+	pub const ParatensorMaximumImmunityPeriod: u16 = 7200;
+	pub const ParatensorMinimumBondsMovingAverage: u64 = 0;
+	pub const ParatensorMaximumBondsMovingAverage: u64 = 1_000_000;
+	pub const ParatensorMinimumTrustThreshold: u16 = 0;
+	pub const ParatensorMaximumTrustThreshold: u16 = u16::MAX;
+	pub const ParatensorMinimumMaxAllowedValidators: u16 = 1;
+	pub const ParatensorMaximumMaxAllowedValidators: u16 = 4096;
+	pub const ParatensorMinimumDelegateTake: u16 = 0;
+	pub const ParatensorMaximumDelegateTake: u16 = 32_767;
+	pub const ParatensorMinimumUnbondingPeriod: u64 = 0;
+	pub const ParatensorMaximumUnbondingPeriod: u64 = 100_800;
 	
 }
 impl pallet_paratensor::Config for Runtime {
@@ -492,6 +549,12 @@ impl pallet_paratensor::Config for Runtime {
 	type Currency = Balances;
 	type InitialRho = ParatensorInitialRho;
 	type InitialKappa = ParatensorInitialKappa;
+	type InitialBondsMovingAverage = ParatensorInitialBondsMovingAverage;
+	type InitialTrustThreshold = ParatensorInitialTrustThreshold;
+	type InitialMaxAllowedValidators = ParatensorInitialMaxAllowedValidators;
+	type InitialDelegateTake = ParatensorInitialDelegateTake;
+	type InitialUnbondingPeriod = ParatensorInitialUnbondingPeriod;
+	type MaxUnbondingChunks = ParatensorMaxUnbondingChunks;
 	type InitialMaxAllowedUids = ParatensorInitialMaxAllowedUids;
 	type InitialIssuance = ParatensorInitialIssuance;
 	type InitialGlobalN = ParatensorInitialGlobalN;
@@ -504,6 +567,8 @@ impl pallet_paratensor::Config for Runtime {
 	type InitialBlocksPerStep = ParatensorInitialBlocksPerStep;
 	type InitialTempo = ParatensorInitialTempo;
 	type InitialDifficulty = ParatensorInitialDifficulty;
+	type MinimumDifficulty = ParatensorMinimumDifficulty;
+	type MaximumDifficulty = ParatensorMaximumDifficulty;
 	type InitialAdjustmentInterval = ParatensorInitialAdjustmentInterval;
 	type InitialTargetRegistrationsPerInterval = ParatensorInitialTargetRegistrationsPerInterval;
 	type InitialIncentivePruningDenominator = ParatensorInitialIncentivePruningDenominator;
@@ -513,6 +578,55 @@ impl pallet_paratensor::Config for Runtime {
 	type InitialMaxRegistrationsPerBlock = ParatensorInitialMaxRegistrationsPerBlock;
 	type InitialStakePruningMin = ParatensorInitialStakePruningMin;
 	type InitialPrunningScore = ParatensorInitialPrunningScore;
+	type InitialServingRateLimit = ParatensorInitialServingRateLimit;
+	type MinimumBlocksPerStep = ParatensorMinimumBlocksPerStep;
+	type MaximumBlocksPerStep = ParatensorMaximumBlocksPerStep;
+	type MinimumTempo = ParatensorMinimumTempo;
+	type MaximumTempo = ParatensorMaximumTempo;
+	type MinimumAdjustmentInterval = ParatensorMinimumAdjustmentInterval;
+	type MaximumAdjustmentInterval = ParatensorMaximumAdjustmentInterval;
+	type MinimumTargetRegistrationsPerInterval = ParatensorMinimumTargetRegistrationsPerInterval;
+	type MaximumTargetRegistrationsPerInterval = ParatensorMaximumTargetRegistrationsPerInterval;
+	type MinimumMaxRegistrationsPerBlock = ParatensorMinimumMaxRegistrationsPerBlock;
+	type MaximumMaxRegistrationsPerBlock = ParatensorMaximumMaxRegistrationsPerBlock;
+	type MinimumActivityCutoff = ParatensorMinimumActivityCutoff;
+	type MaximumActivityCutoff = ParatensorMaximumActivityCutoff;
+	type MinimumRho = ParatensorMinimumRho;
+	type MaximumRho = ParatensorMaximumRho;
+	type MinimumKappa = ParatensorMinimumKappa;
+	type MaximumKappa = ParatensorMaximumKappa;
+	type MinimumMaxAllowedUids = ParatensorMinimumMaxAllowedUids;
+	type MaximumMaxAllowedUids = ParatensorMaximumMaxAllowedUids;
+	type MinimumMinAllowedWeights = ParatensorMinimumMinAllowedWeights;
+	type MaximumMinAllowedWeights = ParatensorMaximumMinAllowedWeights;
+	type MinimumMaxAllowedMaxMinRatio = ParatensorMinimumMaxAllowedMaxMinRatio;
+	type MaximumMaxAllowedMaxMinRatio = ParatensorMaximumMaxAllowedMaxMinRatio;
+	type MinimumValidatorBatchSize = ParatensorMinimumValidatorBatchSize;
+	type MaximumValidatorBatchSize = ParatensorMaximumValidatorBatchSize;
+	type MinimumValidatorSequenceLength = ParatensorMinimumValidatorSequenceLength;
+	type MaximumValidatorSequenceLength = ParatensorMaximumValidatorSequenceLength;
+	type MinimumValidatorEpochLength = ParatensorMinimumValidatorEpochLength;
+	type MaximumValidatorEpochLength = ParatensorMaximumValidatorEpochLength;
+	type MinimumValidatorEpochsPerReset = ParatensorMinimumValidatorEpochsPerReset;
+	type MaximumValidatorEpochsPerReset = ParatensorMaximumValidatorEpochsPerReset;
+	type MinimumIncentivePruningDenominator = ParatensorMinimumIncentivePruningDenominator;
+	type MaximumIncentivePruningDenominator = ParatensorMaximumIncentivePruningDenominator;
+	type MinimumStakePruningDenominator = ParatensorMinimumStakePruningDenominator;
+	type MaximumStakePruningDenominator = ParatensorMaximumStakePruningDenominator;
+	type MinimumStakePruningMin = ParatensorMinimumStakePruningMin;
+	type MaximumStakePruningMin = ParatensorMaximumStakePruningMin;
+	type MinimumImmunityPeriod = ParatensorMinimumImmunityPeriod;
+	type MaximumImmunityPeriod = ParatensorMaximumImmunityPeriod;
+	type MinimumBondsMovingAverage = ParatensorMinimumBondsMovingAverage;
+	type MaximumBondsMovingAverage = ParatensorMaximumBondsMovingAverage;
+	type MinimumTrustThreshold = ParatensorMinimumTrustThreshold;
+	type MaximumTrustThreshold = ParatensorMaximumTrustThreshold;
+	type MinimumMaxAllowedValidators = ParatensorMinimumMaxAllowedValidators;
+	type MaximumMaxAllowedValidators = ParatensorMaximumMaxAllowedValidators;
+	type MinimumDelegateTake = ParatensorMinimumDelegateTake;
+	type MaximumDelegateTake = ParatensorMaximumDelegateTake;
+	type MinimumUnbondingPeriod = ParatensorMinimumUnbondingPeriod;
+	type MaximumUnbondingPeriod = ParatensorMaximumUnbondingPeriod;
 }
 
 // Create the runtime by composing the FRAME pallets that were previously configured.
@@ -670,6 +784,24 @@ impl_runtime_apis! {
 		}
 	}
 
+	impl pallet_paratensor_runtime_api::ParatensorApi<Block, AccountId> for Runtime {
+		fn get_hyperparameter_ranges() -> pallet_paratensor::HyperparameterRanges {
+			Paratensor::get_hyperparameter_ranges()
+		}
+
+		fn get_subnet_hyperparams( netuid: u16 ) -> Option<pallet_paratensor::SubnetHyperparams> {
+			Paratensor::get_subnet_hyperparams( netuid )
+		}
+
+		fn get_unbonding_chunks( coldkey: AccountId ) -> Vec<pallet_paratensor::UnbondingChunk<AccountId>> {
+			Paratensor::get_unbonding_chunks( &coldkey )
+		}
+
+		fn get_neuron_info( netuid: u16, uid: u16 ) -> Option<pallet_paratensor::NeuronInfo<AccountId>> {
+			Paratensor::get_neuron_info( netuid, uid )
+		}
+	}
+
 	impl cumulus_primitives_core::CollectCollationInfo<Block> for Runtime {
 		fn collect_collation_info(header: &<Block as BlockT>::Header) -> cumulus_primitives_core::CollationInfo {
 			ParachainSystem::collect_collation_info(header)